@@ -88,4 +88,124 @@ pub enum LaunchpadError {
     
     #[msg("Invalid time limit - must be in the future")]
     InvalidTimeLimit,
+    
+    #[msg("Supply allocations must add up to the total supply")]
+    InvalidSupplyAllocation,
+    
+    #[msg("Curve vault balance does not match the curve allocation")]
+    CurveVaultMismatch,
+    
+    #[msg("Invalid vesting parameters")]
+    InvalidVestingParams,
+    
+    #[msg("Vesting accounts are required for this launchpad")]
+    MissingVestingAccounts,
+    
+    #[msg("Vesting schedule has not started")]
+    VestingNotStarted,
+    
+    #[msg("No vested tokens available to claim")]
+    NothingToClaim,
+    
+    #[msg("Creator token account is required for an initial buy")]
+    MissingCreatorTokenAccount,
+    
+    #[msg("Only verified creators can create launchpads")]
+    CreatorNotVerified,
+    
+    #[msg("Invalid reputation adjustment - must be between -1000 and 1000")]
+    InvalidReputationAdjustment,
+    
+    #[msg("Launchpad has not failed - deposit cannot be forfeited")]
+    LaunchpadNotFailed,
+    
+    #[msg("Invalid anti-snipe parameters")]
+    InvalidAntiSnipeParams,
+    
+    #[msg("Trading has not started yet")]
+    TradingNotStarted,
+    
+    #[msg("Buy exceeds the launch window limit")]
+    BuyExceedsLaunchLimit,
+    
+    #[msg("Invalid trade limits - caps must be greater than zero")]
+    InvalidTradeLimits,
+    
+    #[msg("Buy exceeds the per-transaction SOL limit")]
+    BuyExceedsTxLimit,
+    
+    #[msg("Buy exceeds the per-wallet token limit")]
+    WalletLimitExceeded,
+    
+    #[msg("Trader position is required for this launchpad")]
+    MissingTraderPosition,
+    
+    #[msg("Invalid presale configuration")]
+    InvalidPresaleConfig,
+    
+    #[msg("Launchpad has no presale")]
+    NoPresale,
+    
+    #[msg("Presale is not open")]
+    PresaleNotOpen,
+    
+    #[msg("Public trading opens after the presale ends")]
+    PresaleActive,
+    
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    
+    #[msg("Presale allocation exceeded")]
+    PresaleAllocationExceeded,
+    
+    #[msg("Invalid trading start time")]
+    InvalidTradingStartTime,
+    
+    #[msg("Trader position still holds tokens")]
+    PositionNotEmpty,
+    
+    #[msg("Invalid referral share - must be at most 10000 bps")]
+    InvalidReferralShare,
+    
+    #[msg("Traders cannot refer themselves")]
+    SelfReferral,
+    
+    #[msg("Invalid fee tiers")]
+    InvalidFeeTiers,
+    
+    #[msg("Fee schedule account required")]
+    MissingFeeSchedule,
+    
+    #[msg("Invalid admin action")]
+    InvalidAdminAction,
+    
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+    
+    #[msg("Sell-only mode - buys are disabled")]
+    SellOnlyMode,
+    
+    #[msg("Launchpad has trades and can no longer be cancelled")]
+    LaunchpadHasTrades,
+    
+    #[msg("Invalid failure deadline")]
+    InvalidFailureDeadline,
+    
+    #[msg("Position counts toward a wallet cap and can't be closed while the launch trades")]
+    PositionLocked,
+    
+    #[msg("Fee increases must go through a timelocked admin action")]
+    FeeIncreaseRequiresTimelock,
+    
+    #[msg("Launchpad has failed - deposit must be forfeited instead")]
+    LaunchpadFailed,
+    
+    #[msg("Vested tokens can't be claimed until the launchpad graduates")]
+    VestingLocked,
+    
+    #[msg("Price history account is required for this launchpad")]
+    MissingPriceHistory,
+    
+    #[msg("Presale price is below the curve cost for this buy")]
+    PresalePriceBelowCurve,
 }
\ No newline at end of file
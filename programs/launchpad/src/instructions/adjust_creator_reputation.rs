@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::{update_reputation_score, MAX_REPUTATION_SCORE};
+
+#[derive(Accounts)]
+pub struct AdjustCreatorReputation<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        constraint = global_state.is_verifier(&authority.key()) @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, creator_profile.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    /// Platform authority or verifier
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AdjustCreatorReputation>, adjustment: i16) -> Result<()> {
+    // Adjustment can at most swing the score across the full range
+    if adjustment.unsigned_abs() > MAX_REPUTATION_SCORE {
+        return Err(LaunchpadError::InvalidReputationAdjustment.into());
+    }
+    
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    creator_profile.reputation_adjustment = adjustment;
+    update_reputation_score(creator_profile);
+    
+    msg!("Reputation adjustment set to {} for {}", adjustment, creator_profile.creator);
+    msg!("Reputation score: {}", creator_profile.reputation_score);
+    
+    Ok(())
+}
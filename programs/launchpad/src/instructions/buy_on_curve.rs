@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, MintTo, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
 use crate::error::LaunchpadError;
-use crate::utils::{calculate_linear_cost, calculate_exponential_cost, calculate_linear_price, calculate_exponential_price, calculate_fee, validate_slippage};
+use crate::utils::{calculate_tokens_for_sol_amount, calculate_current_price, calculate_fee, calculate_sniper_fee_bps, split_referral_fee, resolve_platform_fee_bps, check_public_trading_open, public_trading_start, init_trader_position, track_wallet_buy, validate_slippage, update_price_accumulator};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct BuyOnCurve<'info> {
     #[account(
@@ -18,7 +19,9 @@ pub struct BuyOnCurve<'info> {
         seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
         bump = launchpad.bump,
         has_one = bonding_curve @ LaunchpadError::InvalidPDA,
-        constraint = launchpad.status == LaunchpadStatus::Active @ LaunchpadError::LaunchpadNotActive
+        has_one = mint @ LaunchpadError::InvalidTokenMint,
+        has_one = curve_vault @ LaunchpadError::InvalidPDA,
+        constraint = launchpad.status.allows_buys() @ LaunchpadError::LaunchpadNotActive
     )]
     pub launchpad: Account<'info, LaunchpadState>,
     
@@ -32,6 +35,30 @@ pub struct BuyOnCurve<'info> {
     
     pub mint: Account<'info, anchor_spl::token::Mint>,
     
+    /// Curve vault to transfer purchased tokens from
+    #[account(
+        mut,
+        seeds = [b"curve_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: Account<'info, TokenAccount>,
+    
+    /// Creator profile tracking volume across the creator's launches
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, launchpad.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    /// Optional on-chain candle history for this launchpad
+    #[account(
+        mut,
+        seeds = [PriceHistory::SEEDS, launchpad.key().as_ref()],
+        bump = price_history.load()?.bump,
+    )]
+    pub price_history: Option<AccountLoader<'info, PriceHistory>>,
+    
     /// SOL vault to receive payment
     #[account(
         mut,
@@ -40,6 +67,14 @@ pub struct BuyOnCurve<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
+    /// Creator fee vault receiving anti-snipe surplus fees
+    #[account(
+        mut,
+        seeds = [b"creator_fee_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub creator_fee_vault: SystemAccount<'info>,
+    
     /// Platform fee vault
     #[account(
         mut,
@@ -56,6 +91,32 @@ pub struct BuyOnCurve<'info> {
     )]
     pub trader_token_account: Account<'info, TokenAccount>,
     
+    /// Trader position tracking buys, cost basis and PnL (required when a wallet cap is set)
+    #[account(
+        init_if_needed,
+        payer = trader,
+        space = 8 + TraderPosition::LEN,
+        seeds = [TraderPosition::SEEDS, launchpad.key().as_ref(), trader.key().as_ref()],
+        bump
+    )]
+    pub trader_position: Option<Account<'info, TraderPosition>>,
+    
+    /// Platform fee tiers (required when the fee schedule is enabled)
+    #[account(
+        seeds = [FeeSchedule::SEEDS],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+    
+    /// Optional referrer account credited with a share of the platform fee
+    #[account(
+        mut,
+        seeds = [ReferralAccount::SEEDS, referral_account.referrer.as_ref()],
+        bump = referral_account.bump,
+        constraint = referral_account.referrer != trader.key() @ LaunchpadError::SelfReferral
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>,
+    
     #[account(mut)]
     pub trader: Signer<'info>,
     
@@ -75,27 +136,98 @@ pub fn handler(
         return Err(LaunchpadError::PlatformPaused.into());
     }
     
+    // Buys are disabled while the platform is sell-only
+    if ctx.accounts.global_state.sell_only {
+        return Err(LaunchpadError::SellOnlyMode.into());
+    }
+    
     // Validate minimum SOL amount
     if amount_sol == 0 {
         return Err(LaunchpadError::InsufficientSolAmount.into());
     }
     
+    let clock = Clock::get()?;
     let bonding_curve = &mut ctx.accounts.bonding_curve;
     let launchpad = &mut ctx.accounts.launchpad;
     let global_state = &ctx.accounts.global_state;
     
-    // Calculate fees
-    let platform_fee = calculate_fee(amount_sol, global_state.platform_fee_bps)?;
+    // Public trading opens once any presale has ended
+    check_public_trading_open(launchpad, clock.unix_timestamp)?;
+    
+    // Once candles are tracked, every trade must be recorded
+    if launchpad.price_history.is_some() && ctx.accounts.price_history.is_none() {
+        return Err(LaunchpadError::MissingPriceHistory.into());
+    }
+    
+    // Enforce the per-transaction SOL cap
+    if launchpad.max_sol_per_tx.is_some_and(|max_sol| amount_sol > max_sol) {
+        return Err(LaunchpadError::BuyExceedsTxLimit.into());
+    }
+    
+    // Calculate fees at the launch's current platform fee tier
+    let platform_fee_bps = resolve_platform_fee_bps(
+        global_state,
+        ctx.accounts.fee_schedule.as_deref(),
+        &ctx.accounts.creator_profile,
+        bonding_curve.total_volume,
+        &TradeSide::Buy,
+    )?;
+    let platform_fee = calculate_fee(amount_sol, platform_fee_bps)?;
     let creator_fee = calculate_fee(amount_sol, launchpad.creator_fee_bps)?;
-    let total_fees = platform_fee.checked_add(creator_fee)
+    
+    // Carve the referrer's share out of the platform fee
+    let (platform_fee, referral_fee) = if ctx.accounts.referral_account.is_some() {
+        split_referral_fee(platform_fee, global_state.referral_share_bps)?
+    } else {
+        (platform_fee, 0)
+    };
+    
+    // Anti-snipe protections during the launch window
+    let mut sniper_fee = 0;
+    if let Some(anti_snipe) = &launchpad.anti_snipe {
+        let launch_start = public_trading_start(launchpad)
+            .checked_add(anti_snipe.start_delay_seconds)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        if clock.unix_timestamp < launch_start {
+            return Err(LaunchpadError::TradingNotStarted.into());
+        }
+        
+        // Cap buy size while the limit window is open
+        if anti_snipe.max_buy_sol > 0
+            && clock.unix_timestamp < launch_start.saturating_add(anti_snipe.max_buy_window_seconds)
+            && amount_sol > anti_snipe.max_buy_sol
+        {
+            return Err(LaunchpadError::BuyExceedsLaunchLimit.into());
+        }
+        
+        // Charge the decaying surplus on top of the normal fees
+        let normal_fee_bps = platform_fee_bps
+            .checked_add(launchpad.creator_fee_bps)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        let effective_fee_bps = calculate_sniper_fee_bps(
+            anti_snipe,
+            normal_fee_bps,
+            launch_start,
+            clock.unix_timestamp,
+        );
+        sniper_fee = calculate_fee(amount_sol, effective_fee_bps - normal_fee_bps)?;
+    }
+    
+    let total_fees = platform_fee
+        .checked_add(referral_fee)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?
+        .checked_add(creator_fee)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?
+        .checked_add(sniper_fee)
         .ok_or(LaunchpadError::ArithmeticOverflow)?;
     
     let net_sol_amount = amount_sol.checked_sub(total_fees)
         .ok_or(LaunchpadError::InsufficientSolAmount)?;
     
-    // Calculate tokens to mint based on curve
+    // Calculate tokens to transfer based on curve
     let current_supply = bonding_curve.supply_sold;
-    let tokens_to_mint = calculate_tokens_for_sol_amount(
+    let price_before = bonding_curve.last_price;
+    let tokens_out = calculate_tokens_for_sol_amount(
         net_sol_amount,
         current_supply,
         &bonding_curve.curve_type,
@@ -103,20 +235,40 @@ pub fn handler(
     )?;
     
     // Validate minimum tokens output
-    if tokens_to_mint < min_tokens_out {
+    if tokens_out < min_tokens_out {
         return Err(LaunchpadError::MinTokensNotMet.into());
     }
     
     // Validate slippage
-    validate_slippage(min_tokens_out, tokens_to_mint, max_slippage_bps)?;
+    validate_slippage(min_tokens_out, tokens_out, max_slippage_bps)?;
     
     // Check max supply constraint
-    let new_supply = current_supply.checked_add(tokens_to_mint)
+    let new_supply = current_supply.checked_add(tokens_out)
         .ok_or(LaunchpadError::ArithmeticOverflow)?;
     if new_supply > bonding_curve.curve_params.max_supply {
         return Err(LaunchpadError::MaxSupplyExceeded.into());
     }
     
+    // Track cumulative buys and enforce the per-wallet cap
+    if launchpad.max_tokens_per_wallet.is_some() && ctx.accounts.trader_position.is_none() {
+        return Err(LaunchpadError::MissingTraderPosition.into());
+    }
+    if let Some(trader_position) = ctx.accounts.trader_position.as_mut() {
+        if trader_position.launchpad == Pubkey::default() {
+            let launchpad_key = launchpad.key();
+            init_trader_position(
+                trader_position,
+                launchpad,
+                launchpad_key,
+                ctx.accounts.trader.key(),
+                ctx.bumps.trader_position.ok_or(LaunchpadError::InvalidPDA)?,
+            );
+        }
+        
+        track_wallet_buy(trader_position, launchpad.max_tokens_per_wallet, tokens_out)?;
+        trader_position.record_buy(clock.unix_timestamp, amount_sol, tokens_out);
+    }
+    
     // Transfer SOL from trader to sol_vault
     let transfer_instruction = anchor_lang::system_program::Transfer {
         from: ctx.accounts.trader.to_account_info(),
@@ -130,7 +282,44 @@ pub fn handler(
         net_sol_amount,
     )?;
     
-    // Mint tokens to trader
+    // Route the anti-snipe surplus to the creator fee vault
+    if sniper_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.trader.to_account_info(),
+                    to: ctx.accounts.creator_fee_vault.to_account_info(),
+                },
+            ),
+            sniper_fee,
+        )?;
+    }
+    
+    // Pay the referrer's share into their referral account
+    if let Some(referral_account) = ctx.accounts.referral_account.as_mut() {
+        if referral_fee > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.trader.to_account_info(),
+                        to: referral_account.to_account_info(),
+                    },
+                ),
+                referral_fee,
+            )?;
+        }
+        
+        referral_account.total_earned = referral_account.total_earned
+            .checked_add(referral_fee)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        referral_account.referred_trades = referral_account.referred_trades
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+    
+    // Transfer tokens from the curve vault to trader
     let launchpad_seeds = &[
         LaunchpadState::SEEDS,
         launchpad.mint.as_ref(),
@@ -138,17 +327,17 @@ pub fn handler(
     ];
     let signer_seeds = &[&launchpad_seeds[..]];
     
-    let mint_ctx = CpiContext::new_with_signer(
+    let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        MintTo {
-            mint: ctx.accounts.mint.to_account_info(),
+        Transfer {
+            from: ctx.accounts.curve_vault.to_account_info(),
             to: ctx.accounts.trader_token_account.to_account_info(),
             authority: launchpad.to_account_info(),
         },
         signer_seeds,
     );
     
-    token::mint_to(mint_ctx, tokens_to_mint)?;
+    token::transfer(transfer_ctx, tokens_out)?;
     
     // Update bonding curve state
     bonding_curve.supply_sold = new_supply;
@@ -158,122 +347,84 @@ pub fn handler(
     bonding_curve.fee_collected = bonding_curve.fee_collected
         .checked_add(total_fees)
         .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    bonding_curve.total_volume = bonding_curve.total_volume
+        .checked_add(amount_sol)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    // Track creator volume
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    creator_profile.total_volume = creator_profile.total_volume
+        .checked_add(amount_sol)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
     
-    // Update last price
+    // Credit the outgoing price to the TWAP accumulator, then update last price
+    update_price_accumulator(bonding_curve, clock.unix_timestamp)?;
     bonding_curve.last_price = calculate_current_price(
         new_supply,
         &bonding_curve.curve_type,
         &bonding_curve.curve_params,
     )?;
     
+    // Record the trade in the candle history if tracked
+    if let Some(price_history) = &ctx.accounts.price_history {
+        price_history.load_mut()?.record_trade(
+            clock.unix_timestamp,
+            price_before,
+            bonding_curve.last_price,
+            amount_sol,
+            tokens_out,
+        );
+    }
+    
     // TODO: Handle platform fee distribution to fee_vault
     // This would require converting SOL to WSOL and transferring to the vault
     
     msg!("Buy executed successfully");
     msg!("SOL amount: {}", amount_sol);
     msg!("Net SOL (after fees): {}", net_sol_amount);
-    msg!("Tokens minted: {}", tokens_to_mint);
+    msg!("Tokens out: {}", tokens_out);
     msg!("New supply: {}", new_supply);
     msg!("Current price: {}", bonding_curve.last_price);
     
+    // Emit trade event for indexers
+    emit_cpi!(TradeEvent {
+        trader: ctx.accounts.trader.key(),
+        launchpad: launchpad.key(),
+        mint: launchpad.mint,
+        side: TradeSide::Buy,
+        sol_gross: amount_sol,
+        sol_net: net_sol_amount,
+        platform_fee_bps,
+        platform_fee,
+        creator_fee: creator_fee + sniper_fee,
+        referral_fee,
+        tokens: tokens_out,
+        supply_before: current_supply,
+        supply_after: new_supply,
+        price_after: bonding_curve.last_price,
+        timestamp: clock.unix_timestamp,
+        slot: clock.slot,
+    });
+    
     Ok(())
 }
 
-fn calculate_tokens_for_sol_amount(
-    sol_amount: u64,
-    current_supply: u64,
-    curve_type: &CurveType,
-    curve_params: &CurveParams,
-) -> Result<u64> {
-    match curve_type {
-        CurveType::Linear => {
-            // For linear curve: P(S) = base_price + slope * S
-            // We need to solve: sol_amount = integral from current_supply to (current_supply + tokens)
-            // This is a quadratic equation: slope/2 * tokens^2 + (base_price + slope*current_supply) * tokens - sol_amount = 0
-            
-            let base_price = curve_params.base_price;
-            let slope = curve_params.slope;
-            
-            if slope == 0 {
-                // Simple case: constant price
-                return Ok(sol_amount / base_price);
-            }
-            
-            let current_price = calculate_linear_price(current_supply, base_price, slope)?;
-            let a = slope / 2; // coefficient of tokens^2
-            let b = current_price; // coefficient of tokens
-            let c = sol_amount; // constant term (negated)
-            
-            // Quadratic formula: tokens = (-b + sqrt(b^2 + 4*a*c)) / (2*a)
-            let discriminant = b.checked_mul(b)
-                .ok_or(LaunchpadError::ArithmeticOverflow)?
-                .checked_add(4u64.checked_mul(a)
-                    .ok_or(LaunchpadError::ArithmeticOverflow)?
-                    .checked_mul(c)
-                    .ok_or(LaunchpadError::ArithmeticOverflow)?)
-                .ok_or(LaunchpadError::ArithmeticOverflow)?;
-            
-            let sqrt_discriminant = (discriminant as f64).sqrt() as u64;
-            let tokens = sqrt_discriminant.checked_sub(b)
-                .ok_or(LaunchpadError::ArithmeticUnderflow)?
-                .checked_div(2 * a)
-                .ok_or(LaunchpadError::DivisionByZero)?;
-                
-            Ok(tokens)
-        }
-        CurveType::Exponential => {
-            // For exponential curves, we use iterative approximation
-            // This is simplified - a more sophisticated implementation would use binary search
-            let mut tokens = 0u64;
-            let mut accumulated_cost = 0u64;
-            let step_size = 1000; // tokens per iteration
-            
-            while accumulated_cost < sol_amount && tokens < curve_params.max_supply {
-                let next_tokens = tokens + step_size;
-                let step_cost = calculate_exponential_cost(
-                    current_supply + tokens,
-                    current_supply + next_tokens,
-                    curve_params.base_price,
-                    curve_params.slope, // multiplier
-                    curve_params.step,
-                )?;
-                
-                if accumulated_cost + step_cost <= sol_amount {
-                    tokens = next_tokens;
-                    accumulated_cost += step_cost;
-                } else {
-                    break;
-                }
-            }
-            
-            Ok(tokens)
-        }
-        CurveType::Custom => {
-            // Future implementation
-            Err(LaunchpadError::InvalidCurveParams.into())
-        }
-    }
+#[event]
+pub struct TradeEvent {
+    pub trader: Pubkey,
+    pub launchpad: Pubkey,
+    pub mint: Pubkey,
+    pub side: TradeSide,
+    pub sol_gross: u64,
+    pub sol_net: u64,
+    pub platform_fee_bps: u16,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub referral_fee: u64,
+    pub tokens: u64,
+    pub supply_before: u64,
+    pub supply_after: u64,
+    pub price_after: u64,
+    pub timestamp: i64,
+    pub slot: u64,
 }
-
-fn calculate_current_price(
-    supply: u64,
-    curve_type: &CurveType,
-    curve_params: &CurveParams,
-) -> Result<u64> {
-    match curve_type {
-        CurveType::Linear => {
-            calculate_linear_price(supply, curve_params.base_price, curve_params.slope)
-        }
-        CurveType::Exponential => {
-            calculate_exponential_price(
-                supply,
-                curve_params.base_price,
-                curve_params.slope,
-                curve_params.step,
-            )
-        }
-        CurveType::Custom => {
-            Err(LaunchpadError::InvalidCurveParams.into())
-        }
-    }
-}
\ No newline at end of file
@@ -0,0 +1,333 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::instructions::buy_on_curve::TradeEvent;
+use crate::utils::{resolve_platform_fee_bps, init_trader_position, track_wallet_buy, calculate_presale_tokens, calculate_current_price, calculate_fee, presale_leaf, verify_merkle_proof, update_price_accumulator};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BuyPresale<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump,
+        has_one = bonding_curve @ LaunchpadError::InvalidPDA,
+        has_one = mint @ LaunchpadError::InvalidTokenMint,
+        has_one = curve_vault @ LaunchpadError::InvalidPDA,
+        constraint = launchpad.status.allows_buys() @ LaunchpadError::LaunchpadNotActive
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    #[account(
+        mut,
+        seeds = [BondingCurveState::SEEDS, launchpad.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = launchpad @ LaunchpadError::InvalidPDA
+    )]
+    pub bonding_curve: Account<'info, BondingCurveState>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    /// Curve vault to transfer purchased tokens from
+    #[account(
+        mut,
+        seeds = [b"curve_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: Account<'info, TokenAccount>,
+    
+    /// Creator profile tracking volume across the creator's launches
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, launchpad.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    /// Platform fee tiers (required when the fee schedule is enabled)
+    #[account(
+        seeds = [FeeSchedule::SEEDS],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+    
+    /// Candle history (required once initialized)
+    #[account(
+        mut,
+        seeds = [PriceHistory::SEEDS, launchpad.key().as_ref()],
+        bump = price_history.load()?.bump,
+    )]
+    pub price_history: Option<AccountLoader<'info, PriceHistory>>,
+    
+    /// SOL vault to receive payment
+    #[account(
+        mut,
+        seeds = [b"sol_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// Receipt enforcing the buyer's allocation across presale buys
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + PresaleReceipt::LEN,
+        seeds = [PresaleReceipt::SEEDS, launchpad.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub presale_receipt: Account<'info, PresaleReceipt>,
+    
+    /// Trader position tracking buys against the wallet cap and PnL
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + TraderPosition::LEN,
+        seeds = [TraderPosition::SEEDS, launchpad.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub trader_position: Account<'info, TraderPosition>,
+    
+    /// Buyer's token account to receive tokens
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<BuyPresale>,
+    amount_sol: u64,
+    min_tokens_out: u64,
+    max_allocation: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    // Validate platform is not paused
+    if ctx.accounts.global_state.paused {
+        return Err(LaunchpadError::PlatformPaused.into());
+    }
+    
+    // Buys are disabled while the platform is sell-only
+    if ctx.accounts.global_state.sell_only {
+        return Err(LaunchpadError::SellOnlyMode.into());
+    }
+    
+    // Validate minimum SOL amount
+    if amount_sol == 0 {
+        return Err(LaunchpadError::InsufficientSolAmount.into());
+    }
+    
+    let clock = Clock::get()?;
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    let launchpad = &mut ctx.accounts.launchpad;
+    let global_state = &ctx.accounts.global_state;
+    
+    // Presale must be configured and open
+    let presale = launchpad.presale.clone().ok_or(LaunchpadError::NoPresale)?;
+    if clock.unix_timestamp < presale.start_time || clock.unix_timestamp >= presale.end_time {
+        return Err(LaunchpadError::PresaleNotOpen.into());
+    }
+    
+    // Once candles are tracked, every trade must be recorded
+    if launchpad.price_history.is_some() && ctx.accounts.price_history.is_none() {
+        return Err(LaunchpadError::MissingPriceHistory.into());
+    }
+    
+    // Enforce the per-transaction SOL cap
+    if launchpad.max_sol_per_tx.is_some_and(|max_sol| amount_sol > max_sol) {
+        return Err(LaunchpadError::BuyExceedsTxLimit.into());
+    }
+    
+    // Verify the buyer is on the allowlist with this allocation
+    let leaf = presale_leaf(&ctx.accounts.buyer.key(), max_allocation);
+    if !verify_merkle_proof(&proof, &presale.merkle_root, leaf) {
+        return Err(LaunchpadError::InvalidMerkleProof.into());
+    }
+    
+    // Enforce the per-leaf allocation across all presale buys
+    let presale_receipt = &mut ctx.accounts.presale_receipt;
+    if presale_receipt.launchpad == Pubkey::default() {
+        presale_receipt.launchpad = launchpad.key();
+        presale_receipt.buyer = ctx.accounts.buyer.key();
+        presale_receipt.bump = ctx.bumps.presale_receipt;
+    }
+    let sol_spent = presale_receipt.sol_spent
+        .checked_add(amount_sol)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    if sol_spent > max_allocation {
+        return Err(LaunchpadError::PresaleAllocationExceeded.into());
+    }
+    
+    // Calculate fees
+    let platform_fee_bps = resolve_platform_fee_bps(
+        global_state,
+        ctx.accounts.fee_schedule.as_deref(),
+        &ctx.accounts.creator_profile,
+        bonding_curve.total_volume,
+        &TradeSide::Buy,
+    )?;
+    let platform_fee = calculate_fee(amount_sol, platform_fee_bps)?;
+    let creator_fee = calculate_fee(amount_sol, launchpad.creator_fee_bps)?;
+    let total_fees = platform_fee.checked_add(creator_fee)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    let net_sol_amount = amount_sol.checked_sub(total_fees)
+        .ok_or(LaunchpadError::InsufficientSolAmount)?;
+    
+    // Fixed presale price, or the curve price if none was set
+    let current_supply = bonding_curve.supply_sold;
+    let price_before = bonding_curve.last_price;
+    let tokens_out = calculate_presale_tokens(
+        net_sol_amount,
+        presale.presale_price,
+        current_supply,
+        &bonding_curve.curve_type,
+        &bonding_curve.curve_params,
+    )?;
+    
+    // Validate minimum tokens output
+    if tokens_out == 0 || tokens_out < min_tokens_out {
+        return Err(LaunchpadError::MinTokensNotMet.into());
+    }
+    
+    // Check max supply constraint
+    let new_supply = current_supply.checked_add(tokens_out)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    if new_supply > bonding_curve.curve_params.max_supply {
+        return Err(LaunchpadError::MaxSupplyExceeded.into());
+    }
+    
+    // Presale buys count toward the per-wallet cap on the curve
+    let trader_position = &mut ctx.accounts.trader_position;
+    if trader_position.launchpad == Pubkey::default() {
+        let launchpad_key = launchpad.key();
+        init_trader_position(
+            trader_position,
+            launchpad,
+            launchpad_key,
+            ctx.accounts.buyer.key(),
+            ctx.bumps.trader_position,
+        );
+    }
+    track_wallet_buy(trader_position, launchpad.max_tokens_per_wallet, tokens_out)?;
+    trader_position.record_buy(clock.unix_timestamp, amount_sol, tokens_out);
+    
+    // Transfer SOL from buyer to sol_vault
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            },
+        ),
+        net_sol_amount,
+    )?;
+    
+    // Transfer tokens from the curve vault to buyer
+    let launchpad_seeds = &[
+        LaunchpadState::SEEDS,
+        launchpad.mint.as_ref(),
+        &[launchpad.bump],
+    ];
+    let signer_seeds = &[&launchpad_seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.curve_vault.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: launchpad.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::transfer(transfer_ctx, tokens_out)?;
+    
+    // Update presale receipt
+    presale_receipt.sol_spent = sol_spent;
+    presale_receipt.tokens_received = presale_receipt.tokens_received
+        .checked_add(tokens_out)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    // Update bonding curve state
+    bonding_curve.supply_sold = new_supply;
+    bonding_curve.sol_reserves = bonding_curve.sol_reserves
+        .checked_add(net_sol_amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    bonding_curve.fee_collected = bonding_curve.fee_collected
+        .checked_add(total_fees)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    bonding_curve.total_volume = bonding_curve.total_volume
+        .checked_add(amount_sol)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    // Track creator volume
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    creator_profile.total_volume = creator_profile.total_volume
+        .checked_add(amount_sol)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    // Credit the outgoing price to the TWAP accumulator, then update last price
+    update_price_accumulator(bonding_curve, clock.unix_timestamp)?;
+    bonding_curve.last_price = calculate_current_price(
+        new_supply,
+        &bonding_curve.curve_type,
+        &bonding_curve.curve_params,
+    )?;
+    
+    // Record the trade in the candle history if tracked
+    if let Some(price_history) = &ctx.accounts.price_history {
+        price_history.load_mut()?.record_trade(
+            clock.unix_timestamp,
+            price_before,
+            bonding_curve.last_price,
+            amount_sol,
+            tokens_out,
+        );
+    }
+    
+    msg!("Presale buy executed successfully");
+    msg!("SOL amount: {}", amount_sol);
+    msg!("Tokens out: {}", tokens_out);
+    msg!("Allocation used: {} / {}", sol_spent, max_allocation);
+    
+    // Emit trade event for indexers
+    emit_cpi!(TradeEvent {
+        trader: ctx.accounts.buyer.key(),
+        launchpad: launchpad.key(),
+        mint: launchpad.mint,
+        side: TradeSide::Buy,
+        sol_gross: amount_sol,
+        sol_net: net_sol_amount,
+        platform_fee_bps,
+        platform_fee,
+        creator_fee,
+        referral_fee: 0,
+        tokens: tokens_out,
+        supply_before: current_supply,
+        supply_after: new_supply,
+        price_after: bonding_curve.last_price,
+        timestamp: clock.unix_timestamp,
+        slot: clock.slot,
+    });
+    
+    Ok(())
+}
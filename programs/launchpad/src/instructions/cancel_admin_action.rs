@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PendingAdminAction::SEEDS, &pending_action.action_id.to_le_bytes()],
+        bump = pending_action.bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelAdminAction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pending_action = &ctx.accounts.pending_action;
+    
+    msg!("Admin action {} cancelled", pending_action.action_id);
+    
+    emit_cpi!(AdminActionCancelledEvent {
+        action_id: pending_action.action_id,
+        action: pending_action.action.clone(),
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AdminActionCancelledEvent {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, SetAuthority, Token, TokenAccount};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::{check_launch_cancellable, update_reputation_score};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelLaunchpad<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump,
+        has_one = creator @ LaunchpadError::Unauthorized,
+        has_one = mint @ LaunchpadError::InvalidTokenMint,
+        has_one = bonding_curve @ LaunchpadError::InvalidPDA,
+        has_one = token_vault @ LaunchpadError::InvalidPDA,
+        has_one = curve_vault @ LaunchpadError::InvalidPDA
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [BondingCurveState::SEEDS, launchpad.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = launchpad @ LaunchpadError::InvalidPDA
+    )]
+    pub bonding_curve: Account<'info, BondingCurveState>,
+    
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Token vault holding the LP reserve
+    #[account(mut)]
+    pub token_vault: Account<'info, TokenAccount>,
+    
+    /// Curve vault holding the unsold curve allocation
+    #[account(
+        mut,
+        seeds = [b"curve_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: Account<'info, TokenAccount>,
+    
+    /// Creator fee vault, emptied back to the creator
+    #[account(
+        mut,
+        seeds = [b"creator_fee_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub creator_fee_vault: SystemAccount<'info>,
+    
+    /// Vesting schedule (required when the launch has a vesting allocation)
+    #[account(
+        mut,
+        close = creator,
+        seeds = [VestingSchedule::SEEDS, launchpad.key().as_ref()],
+        bump = vesting_schedule.bump,
+        has_one = launchpad @ LaunchpadError::InvalidPDA
+    )]
+    pub vesting_schedule: Option<Account<'info, VestingSchedule>>,
+    
+    /// Escrow holding the vesting allocation
+    #[account(
+        mut,
+        seeds = [b"vesting_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub vesting_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Candle history, closed along with the launchpad (required if it was created)
+    #[account(
+        mut,
+        close = creator,
+        seeds = [PriceHistory::SEEDS, launchpad.key().as_ref()],
+        bump = price_history.load()?.bump,
+    )]
+    pub price_history: Option<AccountLoader<'info, PriceHistory>>,
+    
+    /// Creator profile, so the cancelled launch doesn't count against reputation
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, creator.key().as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CancelLaunchpad>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    check_launch_cancellable(&ctx.accounts.launchpad, &ctx.accounts.bonding_curve, clock.unix_timestamp)?;
+    
+    if ctx.accounts.launchpad.price_history.is_some() && ctx.accounts.price_history.is_none() {
+        return Err(LaunchpadError::MissingPriceHistory.into());
+    }
+    
+    if ctx.accounts.launchpad.vesting_amount > 0
+        && (ctx.accounts.vesting_schedule.is_none() || ctx.accounts.vesting_vault.is_none())
+    {
+        return Err(LaunchpadError::MissingVestingAccounts.into());
+    }
+    
+    let launchpad = &ctx.accounts.launchpad;
+    let launchpad_key = launchpad.key();
+    let launchpad_seeds = &[
+        LaunchpadState::SEEDS,
+        launchpad.mint.as_ref(),
+        &[launchpad.bump],
+    ];
+    let signer_seeds = &[&launchpad_seeds[..]];
+    
+    // Burn and close the curve vault and token vault
+    let mut tokens_burned = 0u64;
+    for vault in [&ctx.accounts.curve_vault, &ctx.accounts.token_vault] {
+        if vault.amount > 0 {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: vault.to_account_info(),
+                        authority: launchpad.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                vault.amount,
+            )?;
+            tokens_burned = tokens_burned.checked_add(vault.amount)
+                .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        }
+        
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: launchpad.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+    
+    // Burn and close the vesting escrow, signed by the vesting schedule
+    if let (Some(vesting_schedule), Some(vesting_vault)) = (
+        ctx.accounts.vesting_schedule.as_ref(),
+        ctx.accounts.vesting_vault.as_ref(),
+    ) {
+        let vesting_seeds = &[
+            VestingSchedule::SEEDS,
+            launchpad_key.as_ref(),
+            &[vesting_schedule.bump],
+        ];
+        let vesting_signer_seeds = &[&vesting_seeds[..]];
+        
+        if vesting_vault.amount > 0 {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: vesting_vault.to_account_info(),
+                        authority: vesting_schedule.to_account_info(),
+                    },
+                    vesting_signer_seeds,
+                ),
+                vesting_vault.amount,
+            )?;
+            tokens_burned = tokens_burned.checked_add(vesting_vault.amount)
+                .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        }
+        
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vesting_vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: vesting_schedule.to_account_info(),
+            },
+            vesting_signer_seeds,
+        ))?;
+    }
+    
+    // Return the fee vault's rent reserve; no trades means no fees were paid in
+    let fee_vault_lamports = ctx.accounts.creator_fee_vault.lamports();
+    if fee_vault_lamports > 0 {
+        let fee_vault_seeds = &[
+            b"creator_fee_vault".as_ref(),
+            launchpad_key.as_ref(),
+            &[ctx.bumps.creator_fee_vault],
+        ];
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator_fee_vault.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+                &[&fee_vault_seeds[..]],
+            ),
+            fee_vault_lamports,
+        )?;
+    }
+    
+    // The launchpad PDA is closed, so nobody may mint this token again
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: launchpad.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    // A launch cancelled before trading is not counted as a launch
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    creator_profile.launches_count = creator_profile.launches_count.saturating_sub(1);
+    update_reputation_score(creator_profile);
+    
+    // Closing the launchpad account also returns the creation deposit it holds
+    let creation_deposit = ctx.accounts.launchpad.creator_deposit;
+    
+    msg!("Launchpad cancelled: {}", launchpad_key);
+    msg!("Tokens burned: {}", tokens_burned);
+    msg!("Creation deposit refunded: {} lamports", creation_deposit);
+    
+    emit_cpi!(LaunchpadCancelledEvent {
+        launchpad: launchpad_key,
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.creator.key(),
+        tokens_burned,
+        creation_deposit,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct LaunchpadCancelledEvent {
+    pub launchpad: Pubkey,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub tokens_burned: u64,
+    pub creation_deposit: u64,
+    pub timestamp: i64,
+}
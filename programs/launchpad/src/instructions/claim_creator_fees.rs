@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump,
+        has_one = creator @ LaunchpadError::Unauthorized
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    /// Creator fee vault holding accrued creator fees
+    #[account(
+        mut,
+        seeds = [b"creator_fee_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub creator_fee_vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    // Leave the rent-exempt reserve so later small fees can still be received
+    let amount = ctx.accounts.creator_fee_vault.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    
+    if amount == 0 {
+        msg!("No creator fees to claim");
+        return Ok(());
+    }
+    
+    let launchpad_key = ctx.accounts.launchpad.key();
+    let vault_seeds = &[
+        b"creator_fee_vault".as_ref(),
+        launchpad_key.as_ref(),
+        &[ctx.bumps.creator_fee_vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.creator_fee_vault.to_account_info(),
+                to: ctx.accounts.creator.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    msg!("Creator fees claimed: {} lamports", amount);
+    
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(
+        mut,
+        seeds = [ReferralAccount::SEEDS, referrer.key().as_ref()],
+        bump = referral_account.bump,
+        has_one = referrer @ LaunchpadError::Unauthorized
+    )]
+    pub referral_account: Account<'info, ReferralAccount>,
+    
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let referral_account = &mut ctx.accounts.referral_account;
+    
+    let amount = referral_account.total_earned
+        .checked_sub(referral_account.total_claimed)
+        .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+    
+    if amount == 0 {
+        msg!("No referral fees to claim");
+        return Ok(());
+    }
+    
+    // Referral account is program-owned, so lamports can be moved directly
+    **referral_account.to_account_info().try_borrow_mut_lamports()? = referral_account
+        .to_account_info()
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+    
+    **ctx.accounts.referrer.try_borrow_mut_lamports()? = ctx.accounts.referrer.lamports()
+        .checked_add(amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    referral_account.total_claimed = referral_account.total_earned;
+    
+    msg!("Referral fees claimed: {} lamports", amount);
+    msg!("Referrer: {}", ctx.accounts.referrer.key());
+    
+    Ok(())
+}
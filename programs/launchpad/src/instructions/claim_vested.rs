@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::calculate_vested_amount;
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump,
+        has_one = mint @ LaunchpadError::InvalidTokenMint,
+        // Vested tokens were never bought on the curve, so they must not be sold into it
+        constraint = launchpad.status == LaunchpadStatus::Graduated @ LaunchpadError::VestingLocked
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    #[account(
+        mut,
+        seeds = [VestingSchedule::SEEDS, launchpad.key().as_ref()],
+        bump = vesting_schedule.bump,
+        has_one = launchpad @ LaunchpadError::InvalidPDA,
+        has_one = beneficiary @ LaunchpadError::Unauthorized,
+        has_one = vault @ LaunchpadError::InvalidPDA
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    /// Escrow holding the unclaimed vesting allocation
+    #[account(
+        mut,
+        seeds = [b"vesting_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    /// Beneficiary's token account to receive vested tokens
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
+    let clock = Clock::get()?;
+    let launchpad_key = ctx.accounts.launchpad.key();
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    
+    // Graduation-start schedules only begin once the launchpad graduates
+    let start_time = vesting_schedule.start_time
+        .ok_or(LaunchpadError::VestingNotStarted)?;
+    
+    let vested = calculate_vested_amount(
+        vesting_schedule.total_amount,
+        start_time,
+        vesting_schedule.cliff_seconds,
+        vesting_schedule.duration_seconds,
+        clock.unix_timestamp,
+    )?;
+    
+    let claimable = vested.checked_sub(vesting_schedule.claimed_amount)
+        .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+    if claimable == 0 {
+        return Err(LaunchpadError::NothingToClaim.into());
+    }
+    
+    // Transfer vested tokens out of escrow
+    let vesting_seeds = &[
+        VestingSchedule::SEEDS,
+        launchpad_key.as_ref(),
+        &[vesting_schedule.bump],
+    ];
+    let signer_seeds = &[&vesting_seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.beneficiary_token_account.to_account_info(),
+            authority: vesting_schedule.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::transfer(transfer_ctx, claimable)?;
+    
+    vesting_schedule.claimed_amount = vesting_schedule.claimed_amount
+        .checked_add(claimable)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    msg!("Vested tokens claimed: {}", claimable);
+    msg!("Total claimed: {}", vesting_schedule.claimed_amount);
+    msg!("Total vesting: {}", vesting_schedule.total_amount);
+    
+    Ok(())
+}
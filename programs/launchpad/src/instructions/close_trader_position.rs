@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[derive(Accounts)]
+pub struct CloseTraderPosition<'info> {
+    #[account(
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    #[account(
+        mut,
+        close = trader,
+        seeds = [TraderPosition::SEEDS, launchpad.key().as_ref(), trader.key().as_ref()],
+        bump = trader_position.bump,
+        has_one = launchpad @ LaunchpadError::InvalidPDA,
+        has_one = trader @ LaunchpadError::Unauthorized,
+        constraint = trader_position.tokens_held == 0 @ LaunchpadError::PositionNotEmpty
+    )]
+    pub trader_position: Account<'info, TraderPosition>,
+    
+    #[account(mut)]
+    pub trader: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseTraderPosition>) -> Result<()> {
+    // The position holds the cumulative buy count behind the wallet cap, so
+    // closing and reopening it would reset the cap while buys are possible
+    let launchpad = &ctx.accounts.launchpad;
+    if launchpad.max_tokens_per_wallet.is_some()
+        && !matches!(launchpad.status, LaunchpadStatus::Graduated | LaunchpadStatus::Failed)
+    {
+        return Err(LaunchpadError::PositionLocked.into());
+    }
+    
+    let trader_position = &ctx.accounts.trader_position;
+    
+    msg!("Trader position closed");
+    msg!("Launchpad: {}", trader_position.launchpad);
+    msg!("Trades: {}", trader_position.trade_count);
+    msg!("Realized PnL: {}", trader_position.realized_pnl);
+    
+    Ok(())
+}
@@ -1,20 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, InitializeMint};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, Transfer, SyncNative, InitializeMint};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
+use crate::instructions::buy_on_curve::TradeEvent;
 use crate::error::LaunchpadError;
-use crate::utils::{find_launchpad_pda, find_bonding_curve_pda, find_sol_vault_pda, find_token_vault_pda, validate_create_params};
+use crate::utils::{resolve_platform_fee_bps, update_price_accumulator, update_reputation_score, find_launchpad_pda, find_bonding_curve_pda, find_sol_vault_pda, find_token_vault_pda, validate_create_params, calculate_tokens_for_sol_amount, calculate_current_price, calculate_fee};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: CreateLaunchpadParams)]
 pub struct CreateLaunchpad<'info> {
     #[account(
         seeds = [GlobalState::SEEDS],
-        bump = global_state.bump,
-        has_one = authority @ LaunchpadError::Unauthorized
+        bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
     
+    /// Platform fee vault receiving the creation fee
+    #[account(
+        mut,
+        constraint = platform_fee_vault.key() == global_state.fee_vault @ LaunchpadError::InvalidAccountOwner
+    )]
+    pub platform_fee_vault: Account<'info, TokenAccount>,
+    
     #[account(
         init,
         payer = creator,
@@ -38,7 +46,6 @@ pub struct CreateLaunchpad<'info> {
         payer = creator,
         mint::decimals = params.decimals,
         mint::authority = launchpad,
-        mint::freeze_authority = launchpad,
     )]
     pub mint: Account<'info, Mint>,
     
@@ -50,6 +57,14 @@ pub struct CreateLaunchpad<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
+    /// Creator fee vault, funded to rent exemption so small fees can land in it
+    #[account(
+        mut,
+        seeds = [b"creator_fee_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub creator_fee_vault: SystemAccount<'info>,
+    
     /// Token vault for holding tokens for LP provision
     #[account(
         init,
@@ -59,10 +74,79 @@ pub struct CreateLaunchpad<'info> {
     )]
     pub token_vault: Account<'info, TokenAccount>,
     
+    /// Curve vault holding the tokens sold through the bonding curve
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"curve_vault", launchpad.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = launchpad,
+    )]
+    pub curve_vault: Account<'info, TokenAccount>,
+    
+    /// Vesting schedule for the creator allocation (required when params.vesting is set)
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + VestingSchedule::LEN,
+        seeds = [VestingSchedule::SEEDS, launchpad.key().as_ref()],
+        bump
+    )]
+    pub vesting_schedule: Option<Account<'info, VestingSchedule>>,
+    
+    /// Escrow holding the vesting allocation, owned by the vesting schedule PDA
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"vesting_vault", launchpad.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vesting_schedule,
+    )]
+    pub vesting_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Creator's token account for the initial buy (required when params.initial_buy_sol is set)
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Optional candle history, created up front so it includes the initial buy
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PriceHistory::LEN,
+        seeds = [PriceHistory::SEEDS, launchpad.key().as_ref()],
+        bump
+    )]
+    pub price_history: Option<AccountLoader<'info, PriceHistory>>,
+    
+    /// Platform fee tiers (required when the fee schedule is enabled)
+    #[account(
+        seeds = [FeeSchedule::SEEDS],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+    
+    /// Creator profile, created on the creator's first launch
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorProfile::LEN,
+        seeds = [CreatorProfile::SEEDS, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
     #[account(mut)]
     pub creator: Signer<'info>,
     
-    pub authority: Signer<'info>,
+    /// Platform authority co-signer, required only in curated mode
+    pub authority: Option<Signer<'info>>,
     
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -76,50 +160,178 @@ pub fn handler(ctx: Context<CreateLaunchpad>, params: CreateLaunchpadParams) ->
         return Err(LaunchpadError::PlatformPaused.into());
     }
     
+    // The creator's initial buy is a buy like any other
+    if ctx.accounts.global_state.sell_only && params.initial_buy_sol.is_some() {
+        return Err(LaunchpadError::SellOnlyMode.into());
+    }
+    
+    // Curated mode requires the platform authority to co-sign
+    if ctx.accounts.global_state.creation_mode == CreationMode::Curated {
+        let authority = ctx.accounts.authority
+            .as_ref()
+            .ok_or(LaunchpadError::Unauthorized)?;
+        if authority.key() != ctx.accounts.global_state.authority {
+            return Err(LaunchpadError::Unauthorized.into());
+        }
+    }
+    
     // Validate parameters
     validate_create_params(&params)?;
     
+    // Vesting accounts must be supplied when a vesting allocation is requested
+    if params.vesting.is_some()
+        && (ctx.accounts.vesting_schedule.is_none() || ctx.accounts.vesting_vault.is_none())
+    {
+        return Err(LaunchpadError::MissingVestingAccounts.into());
+    }
+    
+    // Creator token account must be supplied when an initial buy is requested
+    if params.initial_buy_sol.is_some() && ctx.accounts.creator_token_account.is_none() {
+        return Err(LaunchpadError::MissingCreatorTokenAccount.into());
+    }
+    
+    // Charge the creation fee into the platform fee vault as WSOL
+    let creation_fee = ctx.accounts.global_state.creation_fee_lamports;
+    if creation_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.platform_fee_vault.to_account_info(),
+                },
+            ),
+            creation_fee,
+        )?;
+        
+        token::sync_native(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SyncNative {
+                account: ctx.accounts.platform_fee_vault.to_account_info(),
+            },
+        ))?;
+    }
+    
+    // Hold the refundable anti-spam deposit on the launchpad account
+    let creation_deposit = ctx.accounts.global_state.creation_deposit_lamports;
+    if creation_deposit > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.launchpad.to_account_info(),
+                },
+            ),
+            creation_deposit,
+        )?;
+    }
+    
+    // Fund the creator fee vault up to rent exemption
+    let vault_rent = Rent::get()?.minimum_balance(0)
+        .saturating_sub(ctx.accounts.creator_fee_vault.lamports());
+    if vault_rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.creator_fee_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+    
     let clock = Clock::get()?;
+    let mint_key = ctx.accounts.mint.key();
+    let bonding_curve_key = ctx.accounts.bonding_curve.key();
     let launchpad = &mut ctx.accounts.launchpad;
     let bonding_curve = &mut ctx.accounts.bonding_curve;
     
     // Initialize launchpad state
-    launchpad.mint = ctx.accounts.mint.key();
+    launchpad.mint = mint_key;
     launchpad.creator = ctx.accounts.creator.key();
     launchpad.sol_vault = ctx.accounts.sol_vault.key();
     launchpad.token_vault = ctx.accounts.token_vault.key();
-    launchpad.bonding_curve = ctx.accounts.bonding_curve.key();
+    launchpad.curve_vault = ctx.accounts.curve_vault.key();
+    launchpad.bonding_curve = bonding_curve_key;
     launchpad.status = LaunchpadStatus::Active;
+    launchpad.resume_status = LaunchpadStatus::Active;
     launchpad.creator_fee_bps = params.creator_fee_bps;
     launchpad.total_supply = params.total_supply;
-    launchpad.graduation_criteria = params.graduation_criteria;
+    launchpad.lp_reserve = params.lp_reserve;
+    launchpad.vesting_amount = params.vesting.as_ref().map_or(0, |vesting| vesting.amount);
+    launchpad.creator_initial_buy_sol = 0;
+    launchpad.creator_initial_buy_tokens = 0;
+    launchpad.creator_position_seeded = false;
+    launchpad.creator_deposit = creation_deposit;
+    launchpad.anti_snipe = params.anti_snipe.clone();
+    launchpad.max_tokens_per_wallet = params.max_tokens_per_wallet;
+    launchpad.max_sol_per_tx = params.max_sol_per_tx;
+    launchpad.presale = params.presale.clone();
+    launchpad.trading_starts_at = params.trading_starts_at;
+    launchpad.failure_deadline = params.failure_deadline;
+    launchpad.graduation_criteria = params.graduation_criteria.clone();
     launchpad.meteora_pool = None;
+    launchpad.price_history = None;
     launchpad.created_at = clock.unix_timestamp;
     launchpad.graduated_at = None;
     launchpad.bump = ctx.bumps.launchpad;
     
+    // Initialize the creator profile on first launch, then count this one
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    if creator_profile.creator == Pubkey::default() {
+        creator_profile.initialize(ctx.accounts.creator.key(), ctx.bumps.creator_profile, clock.unix_timestamp);
+    }
+    
+    // Enforce the verified-creators-only policy if enabled
+    if ctx.accounts.global_state.verified_creators_only && !creator_profile.verified {
+        return Err(LaunchpadError::CreatorNotVerified.into());
+    }
+    
+    creator_profile.launches_count = creator_profile.launches_count
+        .checked_add(1)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    update_reputation_score(creator_profile);
+    
     // Initialize bonding curve state
     bonding_curve.launchpad = launchpad.key();
-    bonding_curve.curve_type = params.curve_type;
-    bonding_curve.curve_params = params.curve_params;
+    bonding_curve.curve_type = params.curve_type.clone();
+    bonding_curve.curve_params = params.curve_params.clone();
     bonding_curve.supply_sold = 0;
     bonding_curve.sol_reserves = 0;
     bonding_curve.virtual_sol_reserves = 0; // Can be set for initial liquidity feel
     bonding_curve.virtual_token_reserves = 0;
     bonding_curve.fee_collected = 0;
+    bonding_curve.total_volume = 0;
     bonding_curve.last_price = params.curve_params.base_price;
+    bonding_curve.price_cumulative = 0;
+    bonding_curve.last_price_update = clock.unix_timestamp;
     bonding_curve.bump = ctx.bumps.bonding_curve;
     
-    // Mint initial token supply to the token vault
-    // This will be used for LP provision after graduation
+    // Mint the fixed supply up front: the curve allocation goes to the curve
+    // vault and the LP reserve to the token vault for use after graduation
     let launchpad_seeds = &[
         LaunchpadState::SEEDS,
-        ctx.accounts.mint.key().as_ref(),
+        mint_key.as_ref(),
         &[launchpad.bump],
     ];
     let signer_seeds = &[&launchpad_seeds[..]];
     
-    let mint_ctx = CpiContext::new_with_signer(
+    let curve_mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.curve_vault.to_account_info(),
+            authority: launchpad.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::mint_to(curve_mint_ctx, params.curve_params.max_supply)?;
+    
+    let lp_mint_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
         MintTo {
             mint: ctx.accounts.mint.to_account_info(),
@@ -129,7 +341,169 @@ pub fn handler(ctx: Context<CreateLaunchpad>, params: CreateLaunchpadParams) ->
         signer_seeds,
     );
     
-    token::mint_to(mint_ctx, params.total_supply)?;
+    token::mint_to(lp_mint_ctx, params.lp_reserve)?;
+    
+    // Escrow the creator vesting allocation
+    if let (Some(vesting), Some(vesting_schedule), Some(vesting_vault)) = (
+        params.vesting.as_ref(),
+        ctx.accounts.vesting_schedule.as_mut(),
+        ctx.accounts.vesting_vault.as_ref(),
+    ) {
+        vesting_schedule.launchpad = launchpad.key();
+        vesting_schedule.beneficiary = ctx.accounts.creator.key();
+        vesting_schedule.vault = vesting_vault.key();
+        vesting_schedule.total_amount = vesting.amount;
+        vesting_schedule.claimed_amount = 0;
+        vesting_schedule.cliff_seconds = vesting.cliff_seconds;
+        vesting_schedule.duration_seconds = vesting.duration_seconds;
+        vesting_schedule.start = vesting.start.clone();
+        vesting_schedule.start_time = match vesting.start {
+            VestingStart::Creation => Some(clock.unix_timestamp),
+            VestingStart::Graduation => None,
+        };
+        vesting_schedule.bump = ctx.bumps.vesting_schedule.ok_or(LaunchpadError::InvalidPDA)?;
+        
+        let vesting_mint_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: vesting_vault.to_account_info(),
+                authority: launchpad.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::mint_to(vesting_mint_ctx, vesting.amount)?;
+    }
+    
+    // Execute the creator's initial buy atomically so it can't be sniped
+    if let (Some(amount_sol), Some(creator_token_account)) = (
+        params.initial_buy_sol,
+        ctx.accounts.creator_token_account.as_ref(),
+    ) {
+        // Same fee logic as buy_on_curve
+        let platform_fee_bps = resolve_platform_fee_bps(
+            &ctx.accounts.global_state,
+            ctx.accounts.fee_schedule.as_deref(),
+            creator_profile,
+            bonding_curve.total_volume,
+            &TradeSide::Buy,
+        )?;
+        let platform_fee = calculate_fee(amount_sol, platform_fee_bps)?;
+        let creator_fee = calculate_fee(amount_sol, params.creator_fee_bps)?;
+        let total_fees = platform_fee.checked_add(creator_fee)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        
+        let net_sol_amount = amount_sol.checked_sub(total_fees)
+            .ok_or(LaunchpadError::InsufficientSolAmount)?;
+        
+        let tokens_out = calculate_tokens_for_sol_amount(
+            net_sol_amount,
+            0,
+            &bonding_curve.curve_type,
+            &bonding_curve.curve_params,
+        )?;
+        
+        if tokens_out == 0 {
+            return Err(LaunchpadError::InsufficientSolAmount.into());
+        }
+        if tokens_out > bonding_curve.curve_params.max_supply {
+            return Err(LaunchpadError::MaxSupplyExceeded.into());
+        }
+        
+        // The creator is bound by the same trade limits as everyone else
+        if params.max_sol_per_tx.is_some_and(|max_sol| amount_sol > max_sol) {
+            return Err(LaunchpadError::BuyExceedsTxLimit.into());
+        }
+        if params.max_tokens_per_wallet.is_some_and(|max_tokens| tokens_out > max_tokens) {
+            return Err(LaunchpadError::WalletLimitExceeded.into());
+        }
+        
+        // Transfer SOL from creator to sol_vault
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.sol_vault.to_account_info(),
+                },
+            ),
+            net_sol_amount,
+        )?;
+        
+        // Transfer tokens from the curve vault to creator
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.curve_vault.to_account_info(),
+                to: creator_token_account.to_account_info(),
+                authority: launchpad.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, tokens_out)?;
+        
+        // Update bonding curve state
+        update_price_accumulator(bonding_curve, clock.unix_timestamp)?;
+        bonding_curve.supply_sold = tokens_out;
+        bonding_curve.sol_reserves = net_sol_amount;
+        bonding_curve.fee_collected = total_fees;
+        bonding_curve.total_volume = amount_sol;
+        bonding_curve.last_price = calculate_current_price(
+            tokens_out,
+            &bonding_curve.curve_type,
+            &bonding_curve.curve_params,
+        )?;
+        
+        // Record the creator's initial position
+        launchpad.creator_initial_buy_sol = amount_sol;
+        launchpad.creator_initial_buy_tokens = tokens_out;
+        
+        creator_profile.total_volume = creator_profile.total_volume
+            .checked_add(amount_sol)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        
+        msg!("Creator initial buy: {} SOL for {} tokens", amount_sol, tokens_out);
+        
+        emit_cpi!(TradeEvent {
+            trader: ctx.accounts.creator.key(),
+            launchpad: launchpad.key(),
+            mint: mint_key,
+            side: TradeSide::Buy,
+            sol_gross: amount_sol,
+            sol_net: net_sol_amount,
+            platform_fee_bps,
+            platform_fee,
+            creator_fee,
+            referral_fee: 0,
+            tokens: tokens_out,
+            supply_before: 0,
+            supply_after: tokens_out,
+            price_after: bonding_curve.last_price,
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+        });
+    }
+    
+    // Start the candle history with the creator's initial buy
+    if let Some(price_history) = &ctx.accounts.price_history {
+        let mut price_history_data = price_history.load_init()?;
+        price_history_data.initialize(
+            launchpad.key(),
+            ctx.bumps.price_history.ok_or(LaunchpadError::InvalidPDA)?,
+        );
+        if launchpad.creator_initial_buy_tokens > 0 {
+            price_history_data.record_trade(
+                clock.unix_timestamp,
+                params.curve_params.base_price,
+                bonding_curve.last_price,
+                launchpad.creator_initial_buy_sol,
+                launchpad.creator_initial_buy_tokens,
+            );
+        }
+        launchpad.price_history = Some(price_history.key());
+    }
     
     msg!("Launchpad created successfully");
     msg!("Mint: {}", ctx.accounts.mint.key());
@@ -137,9 +511,34 @@ pub fn handler(ctx: Context<CreateLaunchpad>, params: CreateLaunchpadParams) ->
     msg!("Token Name: {}", params.name);
     msg!("Token Symbol: {}", params.symbol);
     msg!("Total Supply: {}", params.total_supply);
+    msg!("Curve Allocation: {}", params.curve_params.max_supply);
+    msg!("LP Reserve: {}", params.lp_reserve);
+    msg!("Vesting Allocation: {}", launchpad.vesting_amount);
     msg!("Curve Type: {:?}", params.curve_type);
     msg!("Base Price: {}", params.curve_params.base_price);
     msg!("Creator Fee: {} bps", params.creator_fee_bps);
+    msg!("Creation Fee: {} lamports", creation_fee);
+    msg!("Creation Deposit: {} lamports", creation_deposit);
+    
+    // Emit creation event for indexers
+    emit_cpi!(LaunchpadCreatedEvent {
+        launchpad: launchpad.key(),
+        mint: mint_key,
+        creator: ctx.accounts.creator.key(),
+        creation_fee,
+        creation_deposit,
+        created_at: clock.unix_timestamp,
+    });
     
     Ok(())
+}
+
+#[event]
+pub struct LaunchpadCreatedEvent {
+    pub launchpad: Pubkey,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub creation_fee: u64,
+    pub creation_deposit: u64,
+    pub created_at: i64,
 }
\ No newline at end of file
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::validate_admin_action;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PendingAdminAction::SEEDS, &pending_action.action_id.to_le_bytes()],
+        bump = pending_action.bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+    
+    /// Fee schedule (required for fee tier changes)
+    #[account(
+        mut,
+        seeds = [FeeSchedule::SEEDS],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+    
+    /// Creator profile (required for creator fee overrides)
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, creator_profile.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Option<Account<'info, CreatorProfile>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pending_action = &ctx.accounts.pending_action;
+    
+    if clock.unix_timestamp < pending_action.execute_after {
+        return Err(LaunchpadError::TimelockNotElapsed.into());
+    }
+    
+    // Limits may have changed since the action was queued
+    validate_admin_action(&pending_action.action)?;
+    
+    let global_state = &mut ctx.accounts.global_state;
+    match &pending_action.action {
+        AdminAction::SetPlatformFee { platform_fee_bps } => {
+            global_state.platform_fee_bps = *platform_fee_bps;
+            msg!("Platform fee set to {} bps", platform_fee_bps);
+        }
+        AdminAction::SetFeeTiers { tiers } => {
+            let fee_schedule = ctx.accounts.fee_schedule.as_mut()
+                .ok_or(LaunchpadError::MissingFeeSchedule)?;
+            fee_schedule.tiers = tiers.clone();
+            global_state.fee_schedule_enabled = true;
+            msg!("Fee schedule updated with {} tiers", tiers.len());
+        }
+        AdminAction::TransferAuthority { new_authority } => {
+            global_state.authority = *new_authority;
+            msg!("Authority transferred to {}", new_authority);
+        }
+        AdminAction::SetTreasury { treasury } => {
+            global_state.treasury = *treasury;
+            msg!("Treasury set to {}", treasury);
+        }
+        AdminAction::SetCreatorFeeOverride { creator, platform_fee_bps } => {
+            let creator_profile = ctx.accounts.creator_profile.as_mut()
+                .filter(|creator_profile| creator_profile.creator == *creator)
+                .ok_or(LaunchpadError::InvalidAdminAction)?;
+            creator_profile.platform_fee_override_bps = *platform_fee_bps;
+            msg!("Platform fee override for {}: {:?} bps", creator, platform_fee_bps);
+        }
+    }
+    
+    emit_cpi!(AdminActionExecutedEvent {
+        action_id: pending_action.action_id,
+        action: pending_action.action.clone(),
+        executor: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AdminActionExecutedEvent {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, SyncNative};
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::{check_launch_failed, update_reputation_score};
+
+#[derive(Accounts)]
+pub struct ForfeitCreationDeposit<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump,
+        has_one = bonding_curve @ LaunchpadError::InvalidPDA,
+        constraint = launchpad.status != LaunchpadStatus::Graduated @ LaunchpadError::LaunchpadAlreadyGraduated,
+        constraint = launchpad.status != LaunchpadStatus::Failed @ LaunchpadError::LaunchpadNotActive
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    #[account(
+        seeds = [BondingCurveState::SEEDS, launchpad.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = launchpad @ LaunchpadError::InvalidPDA
+    )]
+    pub bonding_curve: Account<'info, BondingCurveState>,
+    
+    /// Creator profile to record the failed launch against
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, launchpad.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    /// Platform fee vault receiving the forfeited deposit
+    #[account(
+        mut,
+        constraint = platform_fee_vault.key() == global_state.fee_vault @ LaunchpadError::InvalidAccountOwner
+    )]
+    pub platform_fee_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ForfeitCreationDeposit>) -> Result<()> {
+    let clock = Clock::get()?;
+    let launchpad = &mut ctx.accounts.launchpad;
+    
+    // Only launches that missed their failure deadline forfeit the deposit
+    if !check_launch_failed(
+        &ctx.accounts.bonding_curve,
+        &launchpad.graduation_criteria,
+        launchpad.failure_deadline,
+        clock.unix_timestamp,
+    ) {
+        return Err(LaunchpadError::LaunchpadNotFailed.into());
+    }
+    
+    // Failure is terminal: holders can only sell, no buys or graduation
+    launchpad.status = LaunchpadStatus::Failed;
+    
+    // A failed launch counts against the creator's reputation, deposit or not
+    update_reputation_score(&mut ctx.accounts.creator_profile);
+    
+    let deposit = launchpad.creator_deposit;
+    if deposit == 0 {
+        msg!("No deposit to forfeit");
+        return Ok(());
+    }
+    launchpad.creator_deposit = 0;
+    
+    // Move the deposit lamports into the WSOL fee vault
+    let launchpad_info = launchpad.to_account_info();
+    **launchpad_info.try_borrow_mut_lamports()? = launchpad_info.lamports()
+        .checked_sub(deposit)
+        .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+    
+    let fee_vault_info = ctx.accounts.platform_fee_vault.to_account_info();
+    **fee_vault_info.try_borrow_mut_lamports()? = fee_vault_info.lamports()
+        .checked_add(deposit)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative {
+            account: fee_vault_info,
+        },
+    ))?;
+    
+    msg!("Creation deposit forfeited: {} lamports", deposit);
+    msg!("Launchpad: {}", launchpad.key());
+    
+    Ok(())
+}
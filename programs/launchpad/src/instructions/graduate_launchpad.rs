@@ -3,12 +3,14 @@ use anchor_lang::solana_program::{
     instruction::{AccountMeta, Instruction},
     program::{invoke, invoke_signed},
 };
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint, SetAuthority};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
 use crate::error::LaunchpadError;
-use crate::utils::check_graduation_criteria;
+use crate::utils::{check_graduation_criteria, update_reputation_score};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct GraduateLaunchpad<'info> {
     #[account(
@@ -34,6 +36,11 @@ pub struct GraduateLaunchpad<'info> {
     )]
     pub bonding_curve: Account<'info, BondingCurveState>,
     
+    /// Token mint - authority is revoked once liquidity is migrated
+    #[account(
+        mut,
+        constraint = mint.key() == launchpad.mint @ LaunchpadError::InvalidTokenMint
+    )]
     pub mint: Account<'info, Mint>,
     
     /// SOL vault with collected funds
@@ -52,6 +59,22 @@ pub struct GraduateLaunchpad<'info> {
     )]
     pub token_vault: Account<'info, TokenAccount>,
     
+    /// Creator vesting schedule, started at graduation if configured to
+    #[account(
+        mut,
+        seeds = [VestingSchedule::SEEDS, launchpad.key().as_ref()],
+        bump = vesting_schedule.bump,
+    )]
+    pub vesting_schedule: Option<Account<'info, VestingSchedule>>,
+    
+    /// Creator profile to credit with a successful launch
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, launchpad.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
     /// WSOL mint for Meteora pool
     pub wsol_mint: Account<'info, Mint>,
     
@@ -75,12 +98,27 @@ pub struct GraduateLaunchpad<'info> {
     #[account(mut)]
     pub lb_token_mint: UncheckedAccount<'info>,
     
+    /// Creator wallet, receives the deposit refund and LP tokens
+    /// CHECK: Verified against launchpad.creator
+    #[account(
+        mut,
+        address = launchpad.creator @ LaunchpadError::Unauthorized
+    )]
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Platform authority wallet, receives platform LP tokens
+    /// CHECK: Verified against global_state.authority
+    #[account(
+        address = global_state.authority @ LaunchpadError::Unauthorized
+    )]
+    pub platform_authority: UncheckedAccount<'info>,
+    
     /// Creator's LP token account
     #[account(
         init_if_needed,
         payer = authority,
         associated_token::mint = lb_token_mint,
-        associated_token::authority = launchpad.creator,
+        associated_token::authority = creator,
     )]
     pub creator_lp_token_account: Account<'info, TokenAccount>,
     
@@ -89,7 +127,7 @@ pub struct GraduateLaunchpad<'info> {
         init_if_needed,
         payer = authority,
         associated_token::mint = lb_token_mint,
-        associated_token::authority = global_state.authority,
+        associated_token::authority = platform_authority,
     )]
     pub platform_lp_token_account: Account<'info, TokenAccount>,
     
@@ -111,28 +149,74 @@ pub fn handler(
     ctx: Context<GraduateLaunchpad>,
     meteora_config: MeteoraPoolConfig,
 ) -> Result<()> {
-    let clock = Clock::get()?;
-    let bonding_curve = &ctx.accounts.bonding_curve;
-    let launchpad = &mut ctx.accounts.launchpad;
-    
-    // Verify graduation criteria are met
-    if !check_graduation_criteria(
-        bonding_curve,
-        &launchpad.graduation_criteria,
-        clock.unix_timestamp,
-    ) {
-        return Err(LaunchpadError::GraduationCriteriaNotMet.into());
+    // Validate platform is not paused
+    if ctx.accounts.global_state.paused {
+        return Err(LaunchpadError::PlatformPaused.into());
     }
     
-    // Verify authority (creator or admin)
-    if ctx.accounts.authority.key() != launchpad.creator 
-        && ctx.accounts.authority.key() != ctx.accounts.global_state.authority {
-        return Err(LaunchpadError::Unauthorized.into());
+    let clock = Clock::get()?;
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    
+    {
+        let bonding_curve = &ctx.accounts.bonding_curve;
+        let launchpad = &mut ctx.accounts.launchpad;
+        
+        // Verify graduation criteria are met
+        if !check_graduation_criteria(
+            bonding_curve,
+            &launchpad.graduation_criteria,
+            clock.unix_timestamp,
+        ) {
+            return Err(LaunchpadError::GraduationCriteriaNotMet.into());
+        }
+        
+        // Verify authority (creator or admin)
+        if ctx.accounts.authority.key() != launchpad.creator 
+            && ctx.accounts.authority.key() != ctx.accounts.global_state.authority {
+            return Err(LaunchpadError::Unauthorized.into());
+        }
+        
+        // Update launchpad status to graduated
+        launchpad.status = LaunchpadStatus::Graduated;
+        launchpad.graduated_at = Some(clock.unix_timestamp);
+        launchpad.meteora_pool = Some(lb_pair_key);
+        
+        // Start the creator vesting clock if it was waiting on graduation
+        if launchpad.vesting_amount > 0 {
+            let vesting_schedule = ctx.accounts.vesting_schedule
+                .as_mut()
+                .ok_or(LaunchpadError::MissingVestingAccounts)?;
+            if vesting_schedule.start == VestingStart::Graduation {
+                vesting_schedule.start_time = Some(clock.unix_timestamp);
+            }
+        }
+        
+        // Record the successful launch on the creator's track record
+        let creator_profile = &mut ctx.accounts.creator_profile;
+        creator_profile.successful_launches = creator_profile.successful_launches
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        update_reputation_score(creator_profile);
+        
+        // Refund the anti-spam deposit to the creator
+        let deposit = launchpad.creator_deposit;
+        if deposit > 0 {
+            launchpad.creator_deposit = 0;
+            
+            let launchpad_info = launchpad.to_account_info();
+            **launchpad_info.try_borrow_mut_lamports()? = launchpad_info.lamports()
+                .checked_sub(deposit)
+                .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+            
+            **ctx.accounts.creator.try_borrow_mut_lamports()? = ctx.accounts.creator.lamports()
+                .checked_add(deposit)
+                .ok_or(LaunchpadError::ArithmeticOverflow)?;
+            
+            msg!("Creation deposit refunded: {} lamports", deposit);
+        }
     }
     
-    // Update launchpad status to graduated
-    launchpad.status = LaunchpadStatus::Graduated;
-    launchpad.graduated_at = Some(clock.unix_timestamp);
+    let bonding_curve = &ctx.accounts.bonding_curve;
     
     // Step 1: Create Meteora DLMM Pool
     create_meteora_pool(&ctx, &meteora_config)?;
@@ -146,24 +230,33 @@ pub fn handler(
     // Step 4: Distribute LP tokens
     distribute_lp_tokens(&ctx, &liquidity_params)?;
     
-    // Update launchpad with Meteora pool reference
-    launchpad.meteora_pool = Some(ctx.accounts.lb_pair.key());
+    // Step 5: Revoke mint authority so supply is fixed from here on
+    revoke_mint_authority(&ctx)?;
+    
+    let launchpad = &ctx.accounts.launchpad;
     
     msg!("Launchpad graduated successfully!");
     msg!("SOL collected: {}", bonding_curve.sol_reserves);
     msg!("Tokens sold: {}", bonding_curve.supply_sold);
-    msg!("Meteora Pool: {}", ctx.accounts.lb_pair.key());
+    msg!("Meteora Pool: {}", lb_pair_key);
     msg!("Graduation time: {}", clock.unix_timestamp);
     
     // Emit graduation event for indexers
-    emit!(LaunchpadGraduatedEvent {
+    emit_cpi!(LaunchpadGraduatedEvent {
         launchpad: launchpad.key(),
         mint: launchpad.mint,
         creator: launchpad.creator,
         sol_collected: bonding_curve.sol_reserves,
         tokens_sold: bonding_curve.supply_sold,
         graduation_time: clock.unix_timestamp,
-        meteora_pool: Some(ctx.accounts.lb_pair.key()),
+        meteora_pool: Some(lb_pair_key),
+    });
+    
+    emit_cpi!(MintAuthorityRevokedEvent {
+        launchpad: launchpad.key(),
+        mint: launchpad.mint,
+        final_supply: ctx.accounts.mint.supply,
+        revoked_at: clock.unix_timestamp,
     });
     
     Ok(())
@@ -375,6 +468,32 @@ fn distribute_lp_tokens(
     Ok(())
 }
 
+// Helper function to permanently revoke the mint authority
+fn revoke_mint_authority(ctx: &Context<GraduateLaunchpad>) -> Result<()> {
+    let launchpad = &ctx.accounts.launchpad;
+    
+    let launchpad_seeds = &[
+        LaunchpadState::SEEDS,
+        launchpad.mint.as_ref(),
+        &[launchpad.bump],
+    ];
+    let signer_seeds = &[&launchpad_seeds[..]];
+    
+    let set_authority_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        SetAuthority {
+            current_authority: launchpad.to_account_info(),
+            account_or_mint: ctx.accounts.mint.to_account_info(),
+        },
+        signer_seeds,
+    );
+    
+    token::set_authority(set_authority_ctx, AuthorityType::MintTokens, None)?;
+    
+    msg!("Mint authority revoked: {}", ctx.accounts.mint.key());
+    Ok(())
+}
+
 #[event]
 pub struct LaunchpadGraduatedEvent {
     pub launchpad: Pubkey,
@@ -384,4 +503,12 @@ pub struct LaunchpadGraduatedEvent {
     pub tokens_sold: u64,
     pub graduation_time: i64,
     pub meteora_pool: Option<Pubkey>,
+}
+
+#[event]
+pub struct MintAuthorityRevokedEvent {
+    pub launchpad: Pubkey,
+    pub mint: Pubkey,
+    pub final_supply: u64,
+    pub revoked_at: i64,
 }
\ No newline at end of file
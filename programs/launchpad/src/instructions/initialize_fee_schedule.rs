@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[derive(Accounts)]
+pub struct InitializeFeeSchedule<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeSchedule::LEN,
+        seeds = [FeeSchedule::SEEDS],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeFeeSchedule>) -> Result<()> {
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    
+    // Tiers are set, and the schedule enabled, through a timelocked admin action
+    fee_schedule.tiers = Vec::new();
+    fee_schedule.bump = ctx.bumps.fee_schedule;
+    
+    msg!("Fee schedule initialized: {}", fee_schedule.key());
+    
+    Ok(())
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::error::LaunchpadError;
-use crate::utils::find_global_state_pda;
+use crate::utils::{find_global_state_pda, MAX_PLATFORM_FEE_BPS};
 
 #[derive(Accounts)]
 pub struct InitializeGlobalState<'info> {
@@ -36,7 +36,7 @@ pub struct InitializeGlobalState<'info> {
 
 pub fn handler(ctx: Context<InitializeGlobalState>, platform_fee_bps: u16) -> Result<()> {
     // Validate platform fee
-    if platform_fee_bps > 1000 {
+    if platform_fee_bps > MAX_PLATFORM_FEE_BPS {
         return Err(LaunchpadError::InvalidFeeBasisPoints.into());
     }
     
@@ -48,7 +48,20 @@ pub fn handler(ctx: Context<InitializeGlobalState>, platform_fee_bps: u16) -> Re
     global_state.platform_fee_bps = platform_fee_bps;
     global_state.fee_vault = ctx.accounts.fee_vault.key();
     global_state.paused = false;
+    global_state.paused_at = 0;
+    global_state.pause_reason = 0;
+    global_state.sell_only = false;
     global_state.upgrade_authority = ctx.accounts.authority.key();
+    global_state.verified_creators_only = false;
+    global_state.creation_mode = CreationMode::Curated;
+    global_state.creation_fee_lamports = 0;
+    global_state.creation_deposit_lamports = 0;
+    global_state.referral_share_bps = 0;
+    global_state.fee_schedule_enabled = false;
+    global_state.pauser = Pubkey::default();
+    global_state.fee_manager = Pubkey::default();
+    global_state.verifier = Pubkey::default();
+    global_state.treasury = ctx.accounts.authority.key();
     global_state.bump = ctx.bumps.global_state;
     
     msg!("Global state initialized with platform fee: {} bps", platform_fee_bps);
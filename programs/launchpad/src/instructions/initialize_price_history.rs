@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializePriceHistory<'info> {
+    #[account(
+        mut,
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PriceHistory::LEN,
+        seeds = [PriceHistory::SEEDS, launchpad.key().as_ref()],
+        bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePriceHistory>) -> Result<()> {
+    let mut price_history = ctx.accounts.price_history.load_init()?;
+    price_history.initialize(ctx.accounts.launchpad.key(), ctx.bumps.price_history);
+    
+    // From now on every trade has to pass the history so no candle is missed
+    ctx.accounts.launchpad.price_history = Some(ctx.accounts.price_history.key());
+    
+    msg!("Price history initialized for launchpad: {}", ctx.accounts.launchpad.key());
+    msg!("Capacity: {} candles of {}s", PriceHistory::CAPACITY, PriceHistory::CANDLE_SECONDS);
+    
+    Ok(())
+}
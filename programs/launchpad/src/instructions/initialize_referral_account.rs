@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeReferralAccount<'info> {
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferralAccount::LEN,
+        seeds = [ReferralAccount::SEEDS, referrer.key().as_ref()],
+        bump
+    )]
+    pub referral_account: Account<'info, ReferralAccount>,
+    
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeReferralAccount>) -> Result<()> {
+    let referral_account = &mut ctx.accounts.referral_account;
+    
+    referral_account.referrer = ctx.accounts.referrer.key();
+    referral_account.total_earned = 0;
+    referral_account.total_claimed = 0;
+    referral_account.referred_trades = 0;
+    referral_account.bump = ctx.bumps.referral_account;
+    
+    msg!("Referral account initialized for: {}", referral_account.referrer);
+    
+    Ok(())
+}
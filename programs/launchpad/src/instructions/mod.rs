@@ -5,6 +5,29 @@ pub mod sell_to_curve;
 pub mod graduate_launchpad;
 pub mod pause_launchpad;
 pub mod withdraw_fees;
+pub mod verify_curve_vault;
+pub mod claim_vested;
+pub mod set_creator_verification;
+pub mod adjust_creator_reputation;
+pub mod set_verified_creators_only;
+pub mod set_creation_config;
+pub mod forfeit_creation_deposit;
+pub mod initialize_price_history;
+pub mod claim_creator_fees;
+pub mod buy_presale;
+pub mod close_trader_position;
+pub mod initialize_referral_account;
+pub mod claim_referral_fees;
+pub mod set_referral_share;
+pub mod initialize_fee_schedule;
+pub mod set_creator_fee_override;
+pub mod queue_admin_action;
+pub mod execute_admin_action;
+pub mod cancel_admin_action;
+pub mod set_role;
+pub mod set_global_pause;
+pub mod set_sell_only;
+pub mod cancel_launchpad;
 
 pub use initialize_global_state::*;
 pub use create_launchpad::*;
@@ -12,4 +35,27 @@ pub use buy_on_curve::*;
 pub use sell_to_curve::*;
 pub use graduate_launchpad::*;
 pub use pause_launchpad::*;
-pub use withdraw_fees::*;
\ No newline at end of file
+pub use withdraw_fees::*;
+pub use verify_curve_vault::*;
+pub use claim_vested::*;
+pub use set_creator_verification::*;
+pub use adjust_creator_reputation::*;
+pub use set_verified_creators_only::*;
+pub use set_creation_config::*;
+pub use forfeit_creation_deposit::*;
+pub use initialize_price_history::*;
+pub use claim_creator_fees::*;
+pub use buy_presale::*;
+pub use close_trader_position::*;
+pub use initialize_referral_account::*;
+pub use claim_referral_fees::*;
+pub use set_referral_share::*;
+pub use initialize_fee_schedule::*;
+pub use set_creator_fee_override::*;
+pub use queue_admin_action::*;
+pub use execute_admin_action::*;
+pub use cancel_admin_action::*;
+pub use set_role::*;
+pub use set_global_pause::*;
+pub use set_sell_only::*;
+pub use cancel_launchpad::*;
\ No newline at end of file
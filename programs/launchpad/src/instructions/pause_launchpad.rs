@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::LaunchpadError;
+use crate::utils::toggle_launch_pause;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PauseLaunchpad<'info> {
     #[account(
         mut,
         seeds = [GlobalState::SEEDS],
         bump = global_state.bump,
-        has_one = authority @ LaunchpadError::Unauthorized
+        constraint = global_state.is_pauser(&authority.key()) @ LaunchpadError::Unauthorized
     )]
     pub global_state: Account<'info, GlobalState>,
     
@@ -19,26 +21,43 @@ pub struct PauseLaunchpad<'info> {
     )]
     pub launchpad: Account<'info, LaunchpadState>,
     
+    /// Platform authority or pauser
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<PauseLaunchpad>) -> Result<()> {
+    let clock = Clock::get()?;
     let launchpad = &mut ctx.accounts.launchpad;
     
+    // The pauser hot key can only pause
+    if launchpad.status == LaunchpadStatus::Paused
+        && ctx.accounts.authority.key() != ctx.accounts.global_state.authority
+    {
+        return Err(LaunchpadError::Unauthorized.into());
+    }
+    
     // Toggle pause state
-    match launchpad.status {
-        LaunchpadStatus::Active => {
-            launchpad.status = LaunchpadStatus::Paused;
-            msg!("Launchpad paused: {}", launchpad.key());
-        }
-        LaunchpadStatus::Paused => {
-            launchpad.status = LaunchpadStatus::Active;
-            msg!("Launchpad unpaused: {}", launchpad.key());
-        }
-        LaunchpadStatus::Graduated => {
-            return Err(LaunchpadError::LaunchpadAlreadyGraduated.into());
-        }
+    toggle_launch_pause(launchpad)?;
+    if launchpad.status == LaunchpadStatus::Paused {
+        msg!("Launchpad paused: {}", launchpad.key());
+    } else {
+        msg!("Launchpad unpaused: {}", launchpad.key());
     }
     
+    emit_cpi!(LaunchpadPausedEvent {
+        launchpad: launchpad.key(),
+        authority: ctx.accounts.authority.key(),
+        paused: launchpad.status == LaunchpadStatus::Paused,
+        timestamp: clock.unix_timestamp,
+    });
+    
     Ok(())
+}
+
+#[event]
+pub struct LaunchpadPausedEvent {
+    pub launchpad: Pubkey,
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
 }
\ No newline at end of file
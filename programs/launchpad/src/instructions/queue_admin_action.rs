@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::validate_admin_action;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct QueueAdminAction<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingAdminAction::LEN,
+        seeds = [PendingAdminAction::SEEDS, &action_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueAdminAction>, action_id: u64, action: AdminAction) -> Result<()> {
+    // Reject invalid actions now rather than after the delay
+    validate_admin_action(&action)?;
+    
+    let clock = Clock::get()?;
+    let execute_after = clock.unix_timestamp
+        .checked_add(PendingAdminAction::TIMELOCK_SECONDS)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.action_id = action_id;
+    pending_action.action = action;
+    pending_action.proposer = ctx.accounts.authority.key();
+    pending_action.queued_at = clock.unix_timestamp;
+    pending_action.execute_after = execute_after;
+    pending_action.bump = ctx.bumps.pending_action;
+    
+    msg!("Admin action {} queued", action_id);
+    msg!("Executable after: {}", execute_after);
+    
+    emit_cpi!(AdminActionQueuedEvent {
+        action_id,
+        action: pending_action.action.clone(),
+        proposer: pending_action.proposer,
+        execute_after,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AdminActionQueuedEvent {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub proposer: Pubkey,
+    pub execute_after: i64,
+    pub timestamp: i64,
+}
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Burn, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::*;
+use crate::instructions::buy_on_curve::TradeEvent;
 use crate::error::LaunchpadError;
-use crate::utils::{calculate_linear_cost, calculate_exponential_cost, calculate_fee, validate_slippage};
+use crate::utils::{calculate_curve_cost, calculate_current_price, calculate_fee, check_public_trading_open, split_referral_fee, resolve_platform_fee_bps, validate_slippage, update_price_accumulator};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SellToCurve<'info> {
     #[account(
@@ -17,7 +19,9 @@ pub struct SellToCurve<'info> {
         seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
         bump = launchpad.bump,
         has_one = bonding_curve @ LaunchpadError::InvalidPDA,
-        constraint = launchpad.status == LaunchpadStatus::Active @ LaunchpadError::LaunchpadNotActive
+        has_one = mint @ LaunchpadError::InvalidTokenMint,
+        has_one = curve_vault @ LaunchpadError::InvalidPDA,
+        constraint = launchpad.status.allows_sells() @ LaunchpadError::LaunchpadNotActive
     )]
     pub launchpad: Account<'info, LaunchpadState>,
     
@@ -31,6 +35,30 @@ pub struct SellToCurve<'info> {
     
     pub mint: Account<'info, anchor_spl::token::Mint>,
     
+    /// Curve vault to return sold tokens to
+    #[account(
+        mut,
+        seeds = [b"curve_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: Account<'info, TokenAccount>,
+    
+    /// Creator profile tracking volume across the creator's launches
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEEDS, launchpad.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    /// Optional on-chain candle history for this launchpad
+    #[account(
+        mut,
+        seeds = [PriceHistory::SEEDS, launchpad.key().as_ref()],
+        bump = price_history.load()?.bump,
+    )]
+    pub price_history: Option<AccountLoader<'info, PriceHistory>>,
+    
     /// SOL vault to send payment from
     #[account(
         mut,
@@ -39,7 +67,7 @@ pub struct SellToCurve<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
-    /// Trader's token account to burn tokens from
+    /// Trader's token account to return tokens from
     #[account(
         mut,
         associated_token::mint = mint,
@@ -47,6 +75,30 @@ pub struct SellToCurve<'info> {
     )]
     pub trader_token_account: Account<'info, TokenAccount>,
     
+    /// Optional trader position tracking cost basis and PnL
+    #[account(
+        mut,
+        seeds = [TraderPosition::SEEDS, launchpad.key().as_ref(), trader.key().as_ref()],
+        bump = trader_position.bump
+    )]
+    pub trader_position: Option<Account<'info, TraderPosition>>,
+    
+    /// Platform fee tiers (required when the fee schedule is enabled)
+    #[account(
+        seeds = [FeeSchedule::SEEDS],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+    
+    /// Optional referrer account credited with a share of the platform fee
+    #[account(
+        mut,
+        seeds = [ReferralAccount::SEEDS, referral_account.referrer.as_ref()],
+        bump = referral_account.bump,
+        constraint = referral_account.referrer != trader.key() @ LaunchpadError::SelfReferral
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>,
+    
     #[account(mut)]
     pub trader: Signer<'info>,
     
@@ -70,10 +122,19 @@ pub fn handler(
         return Err(LaunchpadError::InsufficientTokenAmount.into());
     }
     
+    let clock = Clock::get()?;
     let bonding_curve = &mut ctx.accounts.bonding_curve;
     let launchpad = &mut ctx.accounts.launchpad;
     let global_state = &ctx.accounts.global_state;
     
+    // Public trading opens once any presale has ended
+    check_public_trading_open(launchpad, clock.unix_timestamp)?;
+    
+    // Once candles are tracked, every trade must be recorded
+    if launchpad.price_history.is_some() && ctx.accounts.price_history.is_none() {
+        return Err(LaunchpadError::MissingPriceHistory.into());
+    }
+    
     // Validate trader has enough tokens
     if ctx.accounts.trader_token_account.amount < amount_tokens {
         return Err(LaunchpadError::InsufficientTokenAmount.into());
@@ -87,21 +148,35 @@ pub fn handler(
     // Calculate SOL amount to return
     let current_supply = bonding_curve.supply_sold;
     let new_supply = current_supply - amount_tokens;
+    let price_before = bonding_curve.last_price;
     
-    let sol_return_gross = calculate_sol_for_token_amount(
-        amount_tokens,
+    let sol_return_gross = calculate_curve_cost(
         new_supply,
         current_supply,
         &bonding_curve.curve_type,
         &bonding_curve.curve_params,
     )?;
     
-    // Calculate fees on the return amount
-    let platform_fee = calculate_fee(sol_return_gross, global_state.platform_fee_bps)?;
+    // Calculate fees on the return amount at the launch's current platform fee tier
+    let platform_fee_bps = resolve_platform_fee_bps(
+        global_state,
+        ctx.accounts.fee_schedule.as_deref(),
+        &ctx.accounts.creator_profile,
+        bonding_curve.total_volume,
+        &TradeSide::Sell,
+    )?;
+    let platform_fee = calculate_fee(sol_return_gross, platform_fee_bps)?;
     let creator_fee = calculate_fee(sol_return_gross, launchpad.creator_fee_bps)?;
     let total_fees = platform_fee.checked_add(creator_fee)
         .ok_or(LaunchpadError::ArithmeticOverflow)?;
     
+    // Carve the referrer's share out of the platform fee
+    let (platform_fee, referral_fee) = if ctx.accounts.referral_account.is_some() {
+        split_referral_fee(platform_fee, global_state.referral_share_bps)?
+    } else {
+        (platform_fee, 0)
+    };
+    
     let sol_return_net = sol_return_gross.checked_sub(total_fees)
         .ok_or(LaunchpadError::ArithmeticUnderflow)?;
     
@@ -118,17 +193,17 @@ pub fn handler(
         return Err(LaunchpadError::InsufficientSolAmount.into());
     }
     
-    // Burn tokens from trader
-    let burn_ctx = CpiContext::new(
+    // Return tokens from trader to the curve vault (supply stays fixed)
+    let transfer_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
-        Burn {
-            mint: ctx.accounts.mint.to_account_info(),
+        Transfer {
             from: ctx.accounts.trader_token_account.to_account_info(),
+            to: ctx.accounts.curve_vault.to_account_info(),
             authority: ctx.accounts.trader.to_account_info(),
         },
     );
     
-    token::burn(burn_ctx, amount_tokens)?;
+    token::transfer(transfer_ctx, amount_tokens)?;
     
     // Transfer SOL from sol_vault to trader
     **ctx.accounts.sol_vault.try_borrow_mut_lamports()? = ctx.accounts.sol_vault.lamports()
@@ -139,13 +214,60 @@ pub fn handler(
         .checked_add(sol_return_net)
         .ok_or(LaunchpadError::ArithmeticOverflow)?;
     
+    // Pay the referrer's share from the fees retained in the SOL vault
+    if let Some(referral_account) = ctx.accounts.referral_account.as_mut() {
+        if referral_fee > 0 {
+            let launchpad_key = launchpad.key();
+            let vault_seeds = &[
+                b"sol_vault".as_ref(),
+                launchpad_key.as_ref(),
+                &[ctx.bumps.sol_vault],
+            ];
+            let signer_seeds = &[&vault_seeds[..]];
+            
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.sol_vault.to_account_info(),
+                        to: referral_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                referral_fee,
+            )?;
+        }
+        
+        referral_account.total_earned = referral_account.total_earned
+            .checked_add(referral_fee)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        referral_account.referred_trades = referral_account.referred_trades
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+    
     // Update bonding curve state
     bonding_curve.supply_sold = new_supply;
     bonding_curve.sol_reserves = bonding_curve.sol_reserves
         .checked_sub(sol_return_gross)
         .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+    bonding_curve.total_volume = bonding_curve.total_volume
+        .checked_add(sol_return_gross)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    // Track creator volume
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    creator_profile.total_volume = creator_profile.total_volume
+        .checked_add(sol_return_gross)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
     
-    // Update last price
+    // Realize PnL on the trader's position if tracked
+    if let Some(trader_position) = ctx.accounts.trader_position.as_mut() {
+        trader_position.record_sell(clock.unix_timestamp, sol_return_net, amount_tokens);
+    }
+    
+    // Credit the outgoing price to the TWAP accumulator, then update last price
+    update_price_accumulator(bonding_curve, clock.unix_timestamp)?;
     bonding_curve.last_price = if new_supply == 0 {
         bonding_curve.curve_params.base_price
     } else {
@@ -156,67 +278,43 @@ pub fn handler(
         )?
     };
     
+    // Record the trade in the candle history if tracked
+    if let Some(price_history) = &ctx.accounts.price_history {
+        price_history.load_mut()?.record_trade(
+            clock.unix_timestamp,
+            price_before,
+            bonding_curve.last_price,
+            sol_return_gross,
+            amount_tokens,
+        );
+    }
+    
     msg!("Sell executed successfully");
-    msg!("Tokens burned: {}", amount_tokens);
+    msg!("Tokens returned: {}", amount_tokens);
     msg!("SOL returned (gross): {}", sol_return_gross);
     msg!("SOL returned (net): {}", sol_return_net);
     msg!("New supply: {}", new_supply);
     msg!("Current price: {}", bonding_curve.last_price);
     
+    // Emit trade event for indexers
+    emit_cpi!(TradeEvent {
+        trader: ctx.accounts.trader.key(),
+        launchpad: launchpad.key(),
+        mint: launchpad.mint,
+        side: TradeSide::Sell,
+        sol_gross: sol_return_gross,
+        sol_net: sol_return_net,
+        platform_fee_bps,
+        platform_fee,
+        creator_fee,
+        referral_fee,
+        tokens: amount_tokens,
+        supply_before: current_supply,
+        supply_after: new_supply,
+        price_after: bonding_curve.last_price,
+        timestamp: clock.unix_timestamp,
+        slot: clock.slot,
+    });
+    
     Ok(())
-}
-
-fn calculate_sol_for_token_amount(
-    token_amount: u64,
-    supply_start: u64, // After sale (lower)
-    supply_end: u64,   // Before sale (higher)
-    curve_type: &CurveType,
-    curve_params: &CurveParams,
-) -> Result<u64> {
-    match curve_type {
-        CurveType::Linear => {
-            calculate_linear_cost(
-                supply_start,
-                supply_end,
-                curve_params.base_price,
-                curve_params.slope,
-            )
-        }
-        CurveType::Exponential => {
-            calculate_exponential_cost(
-                supply_start,
-                supply_end,
-                curve_params.base_price,
-                curve_params.slope,
-                curve_params.step,
-            )
-        }
-        CurveType::Custom => {
-            Err(LaunchpadError::InvalidCurveParams.into())
-        }
-    }
-}
-
-fn calculate_current_price(
-    supply: u64,
-    curve_type: &CurveType,
-    curve_params: &CurveParams,
-) -> Result<u64> {
-    match curve_type {
-        CurveType::Linear => {
-            Ok(curve_params.base_price + curve_params.slope * supply)
-        }
-        CurveType::Exponential => {
-            if curve_params.step == 0 {
-                return Err(LaunchpadError::DivisionByZero.into());
-            }
-            // Simplified exponential calculation
-            let exp_factor = supply / curve_params.step;
-            let price = curve_params.base_price * curve_params.slope.pow(exp_factor as u32);
-            Ok(price)
-        }
-        CurveType::Custom => {
-            Err(LaunchpadError::InvalidCurveParams.into())
-        }
-    }
 }
\ No newline at end of file
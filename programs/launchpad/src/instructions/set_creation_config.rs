@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[derive(Accounts)]
+pub struct SetCreationConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetCreationConfig>, params: CreationConfigParams) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    global_state.creation_mode = params.creation_mode;
+    global_state.creation_fee_lamports = params.creation_fee_lamports;
+    global_state.creation_deposit_lamports = params.creation_deposit_lamports;
+    
+    msg!("Creation mode: {}", match global_state.creation_mode {
+        CreationMode::Curated => "curated",
+        CreationMode::Permissionless => "permissionless",
+    });
+    msg!("Creation fee: {} lamports", global_state.creation_fee_lamports);
+    msg!("Creation deposit: {} lamports", global_state.creation_deposit_lamports);
+    
+    Ok(())
+}
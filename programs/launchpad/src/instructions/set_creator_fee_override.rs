@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::check_fee_override_decrease;
+
+#[derive(Accounts)]
+pub struct SetCreatorFeeOverride<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// Creator profile, created here if the creator has not launched yet
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CreatorProfile::LEN,
+        seeds = [CreatorProfile::SEEDS, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    /// Platform fee tiers (required when the fee schedule is enabled)
+    #[account(
+        seeds = [FeeSchedule::SEEDS],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+    
+    /// CHECK: Only used to derive the creator profile PDA
+    pub creator: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetCreatorFeeOverride>, platform_fee_bps: Option<u16>) -> Result<()> {
+    let clock = Clock::get()?;
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    
+    // Initialize the profile if this is the creator's first interaction
+    if creator_profile.creator == Pubkey::default() {
+        creator_profile.initialize(ctx.accounts.creator.key(), ctx.bumps.creator_profile, clock.unix_timestamp);
+    }
+    
+    // Increases are queued through queue_admin_action instead
+    check_fee_override_decrease(
+        &ctx.accounts.global_state,
+        ctx.accounts.fee_schedule.as_deref(),
+        creator_profile,
+        platform_fee_bps,
+    )?;
+    
+    creator_profile.platform_fee_override_bps = platform_fee_bps;
+    
+    match platform_fee_bps {
+        Some(fee_bps) => msg!("Platform fee override for {}: {} bps", creator_profile.creator, fee_bps),
+        None => msg!("Platform fee override cleared for {}", creator_profile.creator),
+    }
+    
+    Ok(())
+}
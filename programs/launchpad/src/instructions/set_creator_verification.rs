@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::update_reputation_score;
+
+#[derive(Accounts)]
+pub struct SetCreatorVerification<'info> {
+    #[account(
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        constraint = global_state.is_verifier(&authority.key()) @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// Creator profile, created here if the creator has not launched yet
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CreatorProfile::LEN,
+        seeds = [CreatorProfile::SEEDS, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    /// CHECK: Only used to derive the creator profile PDA
+    pub creator: UncheckedAccount<'info>,
+    
+    /// Platform authority or verifier
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetCreatorVerification>, verified: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    
+    // Initialize the profile if this is the creator's first interaction
+    if creator_profile.creator == Pubkey::default() {
+        creator_profile.initialize(ctx.accounts.creator.key(), ctx.bumps.creator_profile, clock.unix_timestamp);
+    }
+    
+    creator_profile.verified = verified;
+    update_reputation_score(creator_profile);
+    
+    msg!("Creator {}: {}", if verified { "verified" } else { "unverified" }, creator_profile.creator);
+    msg!("Reputation score: {}", creator_profile.reputation_score);
+    
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGlobalPause<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        constraint = global_state.is_pauser(&authority.key()) @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// Platform authority or pauser
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGlobalPause>, paused: bool, reason_code: u16) -> Result<()> {
+    let clock = Clock::get()?;
+    let global_state = &mut ctx.accounts.global_state;
+    
+    // The pauser hot key can only pause
+    if !paused && ctx.accounts.authority.key() != global_state.authority {
+        return Err(LaunchpadError::Unauthorized.into());
+    }
+    
+    global_state.paused = paused;
+    global_state.paused_at = clock.unix_timestamp;
+    global_state.pause_reason = if paused { reason_code } else { 0 };
+    
+    if paused {
+        msg!("Platform paused, reason code: {}", reason_code);
+    } else {
+        msg!("Platform unpaused");
+    }
+    
+    emit_cpi!(GlobalPauseEvent {
+        authority: ctx.accounts.authority.key(),
+        paused,
+        reason_code: global_state.pause_reason,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct GlobalPauseEvent {
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub reason_code: u16,
+    pub timestamp: i64,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[derive(Accounts)]
+pub struct SetReferralShare<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetReferralShare>, referral_share_bps: u16) -> Result<()> {
+    // Share is a fraction of the platform fee, so it can't exceed 100%
+    if referral_share_bps > 10000 {
+        return Err(LaunchpadError::InvalidReferralShare.into());
+    }
+    
+    ctx.accounts.global_state.referral_share_bps = referral_share_bps;
+    
+    msg!("Referral share set to {} bps of the platform fee", referral_share_bps);
+    
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetRole>, role: AdminRole, holder: Pubkey) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    // Pubkey::default() revokes the role
+    let previous = match role {
+        AdminRole::Pauser => std::mem::replace(&mut global_state.pauser, holder),
+        AdminRole::FeeManager => std::mem::replace(&mut global_state.fee_manager, holder),
+        AdminRole::Verifier => std::mem::replace(&mut global_state.verifier, holder),
+    };
+    
+    msg!("Role {} rotated: {} -> {}", match role {
+        AdminRole::Pauser => "pauser",
+        AdminRole::FeeManager => "fee manager",
+        AdminRole::Verifier => "verifier",
+    }, previous, holder);
+    
+    emit_cpi!(RoleUpdatedEvent {
+        role,
+        previous,
+        holder,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RoleUpdatedEvent {
+    pub role: AdminRole,
+    pub previous: Pubkey,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
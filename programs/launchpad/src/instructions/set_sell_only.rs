@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::set_launch_sell_only;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSellOnly<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        constraint = global_state.is_pauser(&authority.key()) @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// Launchpad to switch; platform-wide mode is set when omitted
+    #[account(
+        mut,
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Option<Account<'info, LaunchpadState>>,
+    
+    /// Platform authority or pauser
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetSellOnly>, enabled: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // The pauser hot key can only restrict trading
+    if !enabled && ctx.accounts.authority.key() != ctx.accounts.global_state.authority {
+        return Err(LaunchpadError::Unauthorized.into());
+    }
+    
+    let launchpad_key = match ctx.accounts.launchpad.as_mut() {
+        Some(launchpad) => {
+            set_launch_sell_only(launchpad, enabled)?;
+            msg!("Launchpad sell-only {}: {}", if enabled { "enabled" } else { "disabled" }, launchpad.key());
+            Some(launchpad.key())
+        }
+        None => {
+            ctx.accounts.global_state.sell_only = enabled;
+            msg!("Platform sell-only {}", if enabled { "enabled" } else { "disabled" });
+            None
+        }
+    };
+    
+    emit_cpi!(SellOnlyModeEvent {
+        launchpad: launchpad_key,
+        authority: ctx.accounts.authority.key(),
+        enabled,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SellOnlyModeEvent {
+    pub launchpad: Option<Pubkey>, // None for platform-wide mode
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
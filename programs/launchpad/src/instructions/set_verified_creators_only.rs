@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::LaunchpadError;
+
+#[derive(Accounts)]
+pub struct SetVerifiedCreatorsOnly<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalState::SEEDS],
+        bump = global_state.bump,
+        has_one = authority @ LaunchpadError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetVerifiedCreatorsOnly>, enabled: bool) -> Result<()> {
+    ctx.accounts.global_state.verified_creators_only = enabled;
+    
+    msg!("Verified creators only: {}", enabled);
+    
+    Ok(())
+}
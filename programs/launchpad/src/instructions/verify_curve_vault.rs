@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::error::LaunchpadError;
+use crate::utils::check_curve_vault_balance;
+
+#[derive(Accounts)]
+pub struct VerifyCurveVault<'info> {
+    #[account(
+        seeds = [LaunchpadState::SEEDS, launchpad.mint.as_ref()],
+        bump = launchpad.bump,
+        has_one = bonding_curve @ LaunchpadError::InvalidPDA,
+        has_one = curve_vault @ LaunchpadError::InvalidPDA
+    )]
+    pub launchpad: Account<'info, LaunchpadState>,
+    
+    #[account(
+        seeds = [BondingCurveState::SEEDS, launchpad.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = launchpad @ LaunchpadError::InvalidPDA
+    )]
+    pub bonding_curve: Account<'info, BondingCurveState>,
+    
+    /// Curve vault holding the unsold curve allocation
+    #[account(
+        seeds = [b"curve_vault", launchpad.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<VerifyCurveVault>) -> Result<()> {
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    let vault_amount = ctx.accounts.curve_vault.amount;
+    
+    check_curve_vault_balance(
+        vault_amount,
+        bonding_curve.supply_sold,
+        bonding_curve.curve_params.max_supply,
+    )?;
+    
+    msg!("Curve vault consistent");
+    msg!("Vault balance: {}", vault_amount);
+    msg!("Supply sold: {}", bonding_curve.supply_sold);
+    msg!("Curve allocation: {}", bonding_curve.curve_params.max_supply);
+    
+    Ok(())
+}
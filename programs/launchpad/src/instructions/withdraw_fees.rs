@@ -3,13 +3,14 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::*;
 use crate::error::LaunchpadError;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct WithdrawFees<'info> {
     #[account(
         seeds = [GlobalState::SEEDS],
         bump = global_state.bump,
-        has_one = authority @ LaunchpadError::Unauthorized,
-        has_one = fee_vault @ LaunchpadError::InvalidAccountOwner
+        has_one = fee_vault @ LaunchpadError::InvalidAccountOwner,
+        constraint = global_state.is_fee_manager(&authority.key()) @ LaunchpadError::Unauthorized
     )]
     pub global_state: Account<'info, GlobalState>,
     
@@ -19,13 +20,15 @@ pub struct WithdrawFees<'info> {
     )]
     pub fee_vault: Account<'info, TokenAccount>,
     
+    /// Treasury token account; fees can only be withdrawn here
     #[account(
         mut,
         token::mint = fee_vault.mint,
-        token::authority = authority,
+        token::authority = global_state.treasury,
     )]
     pub destination: Account<'info, TokenAccount>,
     
+    /// Platform authority or fee manager
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -33,6 +36,11 @@ pub struct WithdrawFees<'info> {
 }
 
 pub fn handler(ctx: Context<WithdrawFees>) -> Result<()> {
+    // Validate platform is not paused
+    if ctx.accounts.global_state.paused {
+        return Err(LaunchpadError::PlatformPaused.into());
+    }
+    
     let fee_vault = &ctx.accounts.fee_vault;
     let amount = fee_vault.amount;
     
@@ -63,5 +71,22 @@ pub fn handler(ctx: Context<WithdrawFees>) -> Result<()> {
     
     msg!("Fees withdrawn successfully: {} tokens", amount);
     
+    emit_cpi!(FeesWithdrawnEvent {
+        authority: ctx.accounts.authority.key(),
+        fee_vault: ctx.accounts.fee_vault.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     Ok(())
+}
+
+#[event]
+pub struct FeesWithdrawnEvent {
+    pub authority: Pubkey,
+    pub fee_vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
 }
\ No newline at end of file
@@ -61,6 +61,127 @@ pub mod launchpad {
     pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
         instructions::withdraw_fees::handler(ctx)
     }
+
+    pub fn verify_curve_vault(ctx: Context<VerifyCurveVault>) -> Result<()> {
+        instructions::verify_curve_vault::handler(ctx)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        instructions::claim_vested::handler(ctx)
+    }
+
+    pub fn set_creator_verification(
+        ctx: Context<SetCreatorVerification>,
+        verified: bool,
+    ) -> Result<()> {
+        instructions::set_creator_verification::handler(ctx, verified)
+    }
+
+    pub fn adjust_creator_reputation(
+        ctx: Context<AdjustCreatorReputation>,
+        adjustment: i16,
+    ) -> Result<()> {
+        instructions::adjust_creator_reputation::handler(ctx, adjustment)
+    }
+
+    pub fn set_verified_creators_only(
+        ctx: Context<SetVerifiedCreatorsOnly>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_verified_creators_only::handler(ctx, enabled)
+    }
+
+    pub fn set_creation_config(
+        ctx: Context<SetCreationConfig>,
+        params: CreationConfigParams,
+    ) -> Result<()> {
+        instructions::set_creation_config::handler(ctx, params)
+    }
+
+    pub fn forfeit_creation_deposit(ctx: Context<ForfeitCreationDeposit>) -> Result<()> {
+        instructions::forfeit_creation_deposit::handler(ctx)
+    }
+
+    pub fn initialize_price_history(ctx: Context<InitializePriceHistory>) -> Result<()> {
+        instructions::initialize_price_history::handler(ctx)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees::handler(ctx)
+    }
+
+    pub fn buy_presale(
+        ctx: Context<BuyPresale>,
+        amount_sol: u64,
+        min_tokens_out: u64,
+        max_allocation: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::buy_presale::handler(ctx, amount_sol, min_tokens_out, max_allocation, proof)
+    }
+
+    pub fn close_trader_position(ctx: Context<CloseTraderPosition>) -> Result<()> {
+        instructions::close_trader_position::handler(ctx)
+    }
+
+    pub fn initialize_referral_account(ctx: Context<InitializeReferralAccount>) -> Result<()> {
+        instructions::initialize_referral_account::handler(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees::handler(ctx)
+    }
+
+    pub fn set_referral_share(ctx: Context<SetReferralShare>, referral_share_bps: u16) -> Result<()> {
+        instructions::set_referral_share::handler(ctx, referral_share_bps)
+    }
+
+    pub fn initialize_fee_schedule(ctx: Context<InitializeFeeSchedule>) -> Result<()> {
+        instructions::initialize_fee_schedule::handler(ctx)
+    }
+
+    pub fn set_creator_fee_override(
+        ctx: Context<SetCreatorFeeOverride>,
+        platform_fee_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::set_creator_fee_override::handler(ctx, platform_fee_bps)
+    }
+
+    pub fn queue_admin_action(
+        ctx: Context<QueueAdminAction>,
+        action_id: u64,
+        action: AdminAction,
+    ) -> Result<()> {
+        instructions::queue_admin_action::handler(ctx, action_id, action)
+    }
+
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        instructions::execute_admin_action::handler(ctx)
+    }
+
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        instructions::cancel_admin_action::handler(ctx)
+    }
+
+    pub fn set_role(ctx: Context<SetRole>, role: AdminRole, holder: Pubkey) -> Result<()> {
+        instructions::set_role::handler(ctx, role, holder)
+    }
+
+    pub fn set_global_pause(
+        ctx: Context<SetGlobalPause>,
+        paused: bool,
+        reason_code: u16,
+    ) -> Result<()> {
+        instructions::set_global_pause::handler(ctx, paused, reason_code)
+    }
+
+    pub fn set_sell_only(ctx: Context<SetSellOnly>, enabled: bool) -> Result<()> {
+        instructions::set_sell_only::handler(ctx, enabled)
+    }
+
+    pub fn cancel_launchpad(ctx: Context<CancelLaunchpad>) -> Result<()> {
+        instructions::cancel_launchpad::handler(ctx)
+    }
 }
 
 #[cfg(test)]
@@ -119,6 +240,17 @@ mod tests {
         assert_eq!(net_amount, 9500);
     }
 
+    #[test]
+    fn test_referral_fee_split() {
+        // Referral share is carved out of the platform fee
+        let (platform_fee, referral_fee) = split_referral_fee(100, 2000).unwrap();
+        assert_eq!(referral_fee, 20);
+        assert_eq!(platform_fee, 80);
+        assert_eq!(split_referral_fee(100, 0).unwrap(), (100, 0));
+        assert_eq!(split_referral_fee(100, 10000).unwrap(), (0, 100));
+        assert!(split_referral_fee(100, 10001).is_err());
+    }
+
     #[test]
     fn test_fixed_point_arithmetic() {
         // Test basic fixed-point conversion
@@ -161,7 +293,10 @@ mod tests {
             virtual_sol_reserves: 0,
             virtual_token_reserves: 0,
             fee_collected: 100000,
+            total_volume: 0,
             last_price: 1500,
+            price_cumulative: 0,
+            last_price_update: 0,
             bump: 255,
         };
 
@@ -199,6 +334,626 @@ mod tests {
         assert!(meets_criteria); // Current time 1500 > limit 1000, should pass
     }
 
+    #[test]
+    fn test_launch_failure() {
+        let curve_state = BondingCurveState {
+            launchpad: Pubkey::default(),
+            curve_type: CurveType::Linear,
+            curve_params: CurveParams {
+                base_price: 1000,
+                slope: 10,
+                step: 1,
+                max_supply: 1000000,
+                reserved: [0; 4],
+            },
+            supply_sold: 50000,
+            sol_reserves: 5000000, // 5M lamports
+            virtual_sol_reserves: 0,
+            virtual_token_reserves: 0,
+            fee_collected: 100000,
+            total_volume: 0,
+            last_price: 1500,
+            price_cumulative: 0,
+            last_price_update: 0,
+            bump: 255,
+        };
+        
+        // Failure deadline passed without reaching the SOL target
+        let criteria_target = GraduationCriteria {
+            min_sol_raised: Some(6000000), // 6M lamports
+            min_supply_sold: None,
+            time_limit: None,
+            custom_logic: None,
+        };
+        let criteria_sol = GraduationCriteria {
+            min_sol_raised: Some(4000000), // 4M lamports
+            ..criteria_target.clone()
+        };
+        let criteria_time = GraduationCriteria {
+            min_sol_raised: None,
+            time_limit: Some(1000),
+            ..criteria_target.clone()
+        };
+        
+        assert!(!check_launch_failed(&curve_state, &criteria_target, Some(1000), 500)); // Deadline not reached
+        assert!(check_launch_failed(&curve_state, &criteria_target, Some(1000), 1500)); // 5M < 6M after deadline
+        assert!(!check_launch_failed(&curve_state, &criteria_target, None, 1500)); // No deadline, never fails
+        assert!(!check_launch_failed(&curve_state, &criteria_sol, Some(1000), 1500)); // Graduatable, not failed
+        
+        // time_limit is the earliest graduation time, not a failure deadline
+        assert!(!check_launch_failed(&curve_state, &criteria_time, None, 1500));
+        assert!(check_launch_failed(&curve_state, &criteria_time, Some(800), 900)); // Can't graduate until 1000
+        
+        // Forfeiting marks the launch Failed: buys stop, holders can still exit
+        let mut launchpad = sample_launchpad_state();
+        launchpad.graduation_criteria = criteria_target;
+        launchpad.failure_deadline = Some(1000);
+        assert!(check_launch_failed(&curve_state, &launchpad.graduation_criteria, launchpad.failure_deadline, 1500));
+        launchpad.status = LaunchpadStatus::Failed;
+        assert!(!launchpad.status.allows_buys());
+        assert!(launchpad.status.allows_sells());
+        assert!(check_public_trading_open(&launchpad, 1500).is_ok());
+        
+        // Failure is terminal for everything but sells
+        assert!(toggle_launch_pause(&mut launchpad).is_err());
+        assert!(set_launch_sell_only(&mut launchpad, true).is_err());
+        assert!(check_launch_cancellable(&launchpad, &curve_state, 1500).is_err());
+        
+        for status in [LaunchpadStatus::Paused, LaunchpadStatus::Graduated] {
+            assert!(!status.allows_buys());
+            assert!(!status.allows_sells());
+        }
+        assert!(LaunchpadStatus::SellOnly.allows_sells());
+    }
+
+    #[test]
+    fn test_twap_accumulator() {
+        let mut curve_state = BondingCurveState {
+            launchpad: Pubkey::default(),
+            curve_type: CurveType::Linear,
+            curve_params: CurveParams {
+                base_price: 1000,
+                slope: 10,
+                step: 1,
+                max_supply: 1000000,
+                reserved: [0; 4],
+            },
+            supply_sold: 0,
+            sol_reserves: 0,
+            virtual_sol_reserves: 0,
+            virtual_token_reserves: 0,
+            fee_collected: 0,
+            total_volume: 0,
+            last_price: 1000,
+            price_cumulative: 0,
+            last_price_update: 100,
+            bump: 255,
+        };
+        
+        // Price 1000 held for 60s, then a trade moves it to 4000
+        let start = current_price_cumulative(&curve_state, 100).unwrap();
+        update_price_accumulator(&mut curve_state, 160).unwrap();
+        assert_eq!(curve_state.price_cumulative, 60_000);
+        curve_state.last_price = 4000;
+        
+        // Price 4000 held for 20s: TWAP over 80s = (60000 + 80000) / 80 = 1750
+        let end = current_price_cumulative(&curve_state, 180).unwrap();
+        assert_eq!(calculate_twap(start, 100, end, 180).unwrap(), 1750);
+        
+        // A same-second spike doesn't move the accumulator
+        update_price_accumulator(&mut curve_state, 180).unwrap();
+        curve_state.last_price = 1_000_000;
+        update_price_accumulator(&mut curve_state, 180).unwrap();
+        assert_eq!(curve_state.price_cumulative, 140_000);
+        
+        // Zero-length window is rejected
+        assert!(calculate_twap(start, 100, end, 100).is_err());
+    }
+
+    #[test]
+    fn test_sniper_fee_decay() {
+        let anti_snipe = AntiSnipeParams {
+            start_delay_seconds: 10,
+            max_buy_sol: 1_000_000_000,
+            max_buy_window_seconds: 30,
+            sniper_fee_bps: 5000,
+            decay_seconds: 100,
+        };
+        let normal_fee_bps = 400; // 1% platform + 3% creator
+        let launch_start = 1000;
+        
+        // Full sniper fee at launch start
+        assert_eq!(calculate_sniper_fee_bps(&anti_snipe, normal_fee_bps, launch_start, 1000), 5000);
+        
+        // Halfway through the decay: 400 + 4600 / 2
+        assert_eq!(calculate_sniper_fee_bps(&anti_snipe, normal_fee_bps, launch_start, 1050), 2700);
+        
+        // Back to normal once the decay window has passed
+        assert_eq!(calculate_sniper_fee_bps(&anti_snipe, normal_fee_bps, launch_start, 1100), 400);
+        assert_eq!(calculate_sniper_fee_bps(&anti_snipe, normal_fee_bps, launch_start, 5000), 400);
+    }
+
+    #[test]
+    fn test_presale_merkle_proof() {
+        use anchor_lang::solana_program::hash::hashv;
+        
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        let carol = Pubkey::new_unique();
+        
+        let leaf_alice = presale_leaf(&alice, 1_000_000_000);
+        let leaf_bob = presale_leaf(&bob, 2_000_000_000);
+        let leaf_carol = presale_leaf(&carol, 500_000_000);
+        
+        let hash_pair = |a: [u8; 32], b: [u8; 32]| {
+            if a <= b { hashv(&[&a, &b]).to_bytes() } else { hashv(&[&b, &a]).to_bytes() }
+        };
+        
+        // Three-leaf tree: root = H(H(alice, bob), carol)
+        let node_ab = hash_pair(leaf_alice, leaf_bob);
+        let root = hash_pair(node_ab, leaf_carol);
+        
+        assert!(verify_merkle_proof(&[leaf_bob, leaf_carol], &root, leaf_alice));
+        assert!(verify_merkle_proof(&[leaf_alice, leaf_carol], &root, leaf_bob));
+        assert!(verify_merkle_proof(&[node_ab], &root, leaf_carol));
+        
+        // Claiming a larger allocation than the leaf fails
+        let inflated = presale_leaf(&alice, 5_000_000_000);
+        assert!(!verify_merkle_proof(&[leaf_bob, leaf_carol], &root, inflated));
+        
+        // Wrong wallet fails
+        let mallory = presale_leaf(&Pubkey::new_unique(), 1_000_000_000);
+        assert!(!verify_merkle_proof(&[leaf_bob, leaf_carol], &root, mallory));
+    }
+
+    #[test]
+    fn test_presale_pricing() {
+        let curve_params = CurveParams {
+            base_price: 1000,
+            slope: 10,
+            step: 1,
+            max_supply: 1000000,
+            reserved: [0; 4],
+        };
+        
+        // Without a fixed price the presale buys on the curve
+        let curve_tokens = calculate_tokens_for_sol_amount(150_000, 0, &CurveType::Linear, &curve_params).unwrap();
+        assert_eq!(
+            calculate_presale_tokens(150_000, None, 0, &CurveType::Linear, &curve_params).unwrap(),
+            curve_tokens
+        );
+        
+        // 100 tokens from zero supply cost 1000 * 100 + 10 * 100^2 / 2 = 150,000 on the curve
+        assert_eq!(calculate_curve_cost(0, 100, &CurveType::Linear, &curve_params).unwrap(), 150_000);
+        assert_eq!(
+            calculate_presale_tokens(150_000, Some(1_500), 0, &CurveType::Linear, &curve_params).unwrap(),
+            100
+        );
+        
+        // A fixed price that undercuts the curve would leave sells underfunded
+        assert!(calculate_presale_tokens(100_000, Some(1_000), 0, &CurveType::Linear, &curve_params).is_err());
+        assert!(calculate_presale_tokens(150_000, Some(1_500), 10_000, &CurveType::Linear, &curve_params).is_err());
+        assert!(calculate_presale_tokens(150_000, Some(0), 0, &CurveType::Linear, &curve_params).is_err());
+    }
+
+    fn sample_launchpad_state() -> LaunchpadState {
+        LaunchpadState {
+            mint: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            sol_vault: Pubkey::new_unique(),
+            token_vault: Pubkey::new_unique(),
+            curve_vault: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            status: LaunchpadStatus::Active,
+            resume_status: LaunchpadStatus::Active,
+            creator_fee_bps: 100,
+            total_supply: 1_000_000,
+            lp_reserve: 0,
+            vesting_amount: 0,
+            creator_initial_buy_sol: 0,
+            creator_initial_buy_tokens: 0,
+            creator_position_seeded: false,
+            creator_deposit: 0,
+            anti_snipe: None,
+            max_tokens_per_wallet: None,
+            max_sol_per_tx: None,
+            presale: None,
+            trading_starts_at: None,
+            failure_deadline: None,
+            graduation_criteria: GraduationCriteria {
+                min_sol_raised: None,
+                min_supply_sold: None,
+                time_limit: None,
+                custom_logic: None,
+            },
+            meteora_pool: None,
+            price_history: None,
+            created_at: 1_000,
+            graduated_at: None,
+            bump: 255,
+        }
+    }
+
+    fn empty_trader_position() -> TraderPosition {
+        TraderPosition {
+            launchpad: Pubkey::default(),
+            trader: Pubkey::default(),
+            tokens_bought: 0,
+            tokens_held: 0,
+            cost_basis: 0,
+            total_sol_spent: 0,
+            total_sol_received: 0,
+            realized_pnl: 0,
+            trade_count: 0,
+            first_trade_at: 0,
+            last_trade_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_wallet_buy_tracking() {
+        let mut launchpad = sample_launchpad_state();
+        launchpad.max_tokens_per_wallet = Some(1_000);
+        launchpad.creator_initial_buy_sol = 5_000;
+        launchpad.creator_initial_buy_tokens = 600;
+        
+        // The creator's position starts with their initial buy
+        let mut creator_position = empty_trader_position();
+        let creator = launchpad.creator;
+        init_trader_position(&mut creator_position, &mut launchpad, Pubkey::new_unique(), creator, 254);
+        assert_eq!(creator_position.tokens_bought, 600);
+        assert_eq!(creator_position.tokens_held, 600);
+        assert_eq!(creator_position.total_sol_spent, 5_000);
+        assert!(launchpad.creator_position_seeded);
+        assert!(track_wallet_buy(&mut creator_position, launchpad.max_tokens_per_wallet, 400).is_ok());
+        assert!(track_wallet_buy(&mut creator_position, launchpad.max_tokens_per_wallet, 1).is_err());
+        
+        // A reopened creator position does not count the initial buy twice
+        let mut reopened_position = empty_trader_position();
+        init_trader_position(&mut reopened_position, &mut launchpad, Pubkey::new_unique(), creator, 254);
+        assert_eq!(reopened_position.tokens_bought, 0);
+        assert_eq!(reopened_position.tokens_held, 0);
+        assert_eq!(reopened_position.cost_basis, 0);
+        
+        // Other traders start empty; presale and curve buys share the same cap
+        let mut position = empty_trader_position();
+        init_trader_position(&mut position, &mut launchpad, Pubkey::new_unique(), Pubkey::new_unique(), 254);
+        assert_eq!(position.tokens_bought, 0);
+        assert!(track_wallet_buy(&mut position, launchpad.max_tokens_per_wallet, 700).is_ok());
+        assert!(track_wallet_buy(&mut position, launchpad.max_tokens_per_wallet, 301).is_err());
+        assert!(track_wallet_buy(&mut position, None, 10_000).is_ok());
+        
+        // The anti-snipe window starts when public trading opens
+        assert_eq!(public_trading_start(&launchpad), 1_000);
+        launchpad.trading_starts_at = Some(2_000);
+        assert_eq!(public_trading_start(&launchpad), 2_000);
+        launchpad.presale = Some(PresaleConfig {
+            merkle_root: [1u8; 32],
+            start_time: 1_500,
+            end_time: 3_000,
+            presale_price: None,
+        });
+        assert_eq!(public_trading_start(&launchpad), 3_000);
+    }
+
+    #[test]
+    fn test_public_trading_open() {
+        let mut launchpad = sample_launchpad_state();
+        
+        // No presale or schedule: open immediately
+        assert!(check_public_trading_open(&launchpad, 0).is_ok());
+        
+        // Scheduled start
+        launchpad.trading_starts_at = Some(2_000);
+        assert!(check_public_trading_open(&launchpad, 1_999).is_err());
+        assert!(check_public_trading_open(&launchpad, 2_000).is_ok());
+        
+        // Presale holds the curve closed until it ends
+        launchpad.trading_starts_at = None;
+        launchpad.presale = Some(PresaleConfig {
+            merkle_root: [1u8; 32],
+            start_time: 1_500,
+            end_time: 3_000,
+            presale_price: None,
+        });
+        assert!(check_public_trading_open(&launchpad, 1_600).is_err());
+        assert!(check_public_trading_open(&launchpad, 2_999).is_err());
+        assert!(check_public_trading_open(&launchpad, 3_000).is_ok());
+    }
+
+    #[test]
+    fn test_trader_position_pnl() {
+        let mut position = empty_trader_position();
+        
+        // Two buys at different prices average the entry
+        position.record_buy(100, 1_000, 100);
+        position.record_buy(200, 3_000, 100);
+        assert_eq!(position.tokens_held, 200);
+        assert_eq!(position.cost_basis, 4_000);
+        assert_eq!(position.average_entry_price(), 20);
+        assert_eq!(position.first_trade_at, 100);
+        
+        // Selling half at 30/token realizes (3000 - 2000)
+        position.record_sell(300, 3_000, 100);
+        assert_eq!(position.realized_pnl, 1_000);
+        assert_eq!(position.cost_basis, 2_000);
+        assert_eq!(position.tokens_held, 100);
+        
+        // Selling more than held only realizes the tracked portion
+        position.record_sell(400, 1_000, 200);
+        assert_eq!(position.realized_pnl, 1_000 + 500 - 2_000);
+        assert_eq!(position.tokens_held, 0);
+        assert_eq!(position.cost_basis, 0);
+        assert_eq!(position.average_entry_price(), 0);
+        
+        assert_eq!(position.total_sol_spent, 4_000);
+        assert_eq!(position.total_sol_received, 4_000);
+        assert_eq!(position.trade_count, 4);
+        assert_eq!(position.first_trade_at, 100);
+        assert_eq!(position.last_trade_at, 400);
+    }
+
+    #[test]
+    fn test_launch_status_transitions() {
+        let mut launchpad = sample_launchpad_state();
+        
+        // Sell-only toggles only between live statuses
+        set_launch_sell_only(&mut launchpad, true).unwrap();
+        assert!(launchpad.status == LaunchpadStatus::SellOnly);
+        set_launch_sell_only(&mut launchpad, true).unwrap();
+        assert!(launchpad.status == LaunchpadStatus::SellOnly);
+        set_launch_sell_only(&mut launchpad, false).unwrap();
+        assert!(launchpad.status == LaunchpadStatus::Active);
+        
+        // Pausing a sell-only launch resumes it as sell-only
+        set_launch_sell_only(&mut launchpad, true).unwrap();
+        toggle_launch_pause(&mut launchpad).unwrap();
+        assert!(launchpad.status == LaunchpadStatus::Paused);
+        assert!(set_launch_sell_only(&mut launchpad, false).is_err());
+        toggle_launch_pause(&mut launchpad).unwrap();
+        assert!(launchpad.status == LaunchpadStatus::SellOnly);
+        
+        set_launch_sell_only(&mut launchpad, false).unwrap();
+        toggle_launch_pause(&mut launchpad).unwrap();
+        toggle_launch_pause(&mut launchpad).unwrap();
+        assert!(launchpad.status == LaunchpadStatus::Active);
+        
+        // Terminal statuses cannot be paused or made sell-only
+        for status in [LaunchpadStatus::Graduated, LaunchpadStatus::Failed] {
+            launchpad.status = status.clone();
+            assert!(set_launch_sell_only(&mut launchpad, true).is_err());
+            assert!(set_launch_sell_only(&mut launchpad, false).is_err());
+            assert!(toggle_launch_pause(&mut launchpad).is_err());
+            assert!(launchpad.status == status);
+        }
+    }
+
+    #[test]
+    fn test_launch_cancellable() {
+        let mut launchpad = sample_launchpad_state();
+        launchpad.graduation_criteria.min_sol_raised = Some(1_000_000);
+        launchpad.failure_deadline = Some(5_000);
+        let mut curve_state = BondingCurveState {
+            launchpad: Pubkey::default(),
+            curve_type: CurveType::Linear,
+            curve_params: CurveParams {
+                base_price: 1000,
+                slope: 10,
+                step: 1,
+                max_supply: 1000000,
+                reserved: [0; 4],
+            },
+            supply_sold: 0,
+            sol_reserves: 0,
+            virtual_sol_reserves: 0,
+            virtual_token_reserves: 0,
+            fee_collected: 0,
+            total_volume: 0,
+            last_price: 1000,
+            price_cumulative: 0,
+            last_price_update: 0,
+            bump: 255,
+        };
+        
+        // Untraded launches can be cancelled until their failure deadline
+        assert!(check_launch_cancellable(&launchpad, &curve_state, 4_999).is_ok());
+        assert!(check_launch_cancellable(&launchpad, &curve_state, 5_000).is_err());
+        launchpad.failure_deadline = None;
+        assert!(check_launch_cancellable(&launchpad, &curve_state, 5_000).is_ok());
+        
+        // A trade that was fully sold back still blocks cancellation
+        curve_state.total_volume = 1_000;
+        assert!(check_launch_cancellable(&launchpad, &curve_state, 0).is_err());
+        curve_state.total_volume = 0;
+        curve_state.supply_sold = 1;
+        assert!(check_launch_cancellable(&launchpad, &curve_state, 0).is_err());
+        curve_state.supply_sold = 0;
+        
+        // Paused and sell-only launches can be cancelled, terminal ones cannot
+        for status in [LaunchpadStatus::Paused, LaunchpadStatus::SellOnly] {
+            launchpad.status = status;
+            assert!(check_launch_cancellable(&launchpad, &curve_state, 0).is_ok());
+        }
+        for status in [LaunchpadStatus::Graduated, LaunchpadStatus::Failed] {
+            launchpad.status = status;
+            assert!(check_launch_cancellable(&launchpad, &curve_state, 0).is_err());
+        }
+    }
+
+    fn sample_global_state() -> GlobalState {
+        GlobalState {
+            authority: Pubkey::new_unique(),
+            platform_fee_bps: 200,
+            fee_vault: Pubkey::new_unique(),
+            paused: false,
+            paused_at: 0,
+            pause_reason: 0,
+            sell_only: false,
+            upgrade_authority: Pubkey::new_unique(),
+            verified_creators_only: false,
+            creation_mode: CreationMode::Curated,
+            creation_fee_lamports: 0,
+            creation_deposit_lamports: 0,
+            referral_share_bps: 0,
+            fee_schedule_enabled: false,
+            pauser: Pubkey::default(),
+            fee_manager: Pubkey::new_unique(),
+            verifier: Pubkey::default(),
+            treasury: Pubkey::new_unique(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_fee_schedule_tiers() {
+        let tiers = vec![
+            FeeTier { min_volume: 0, buy_fee_bps: 100, sell_fee_bps: 150 },
+            FeeTier { min_volume: 10_000_000_000, buy_fee_bps: 75, sell_fee_bps: 100 },
+            FeeTier { min_volume: 100_000_000_000, buy_fee_bps: 50, sell_fee_bps: 50 },
+        ];
+        assert!(validate_fee_tiers(&tiers).is_ok());
+        
+        let schedule = FeeSchedule { tiers: tiers.clone(), bump: 255 };
+        assert_eq!(schedule.fee_bps(0, &TradeSide::Buy), 100);
+        assert_eq!(schedule.fee_bps(0, &TradeSide::Sell), 150);
+        assert_eq!(schedule.fee_bps(9_999_999_999, &TradeSide::Buy), 100);
+        assert_eq!(schedule.fee_bps(10_000_000_000, &TradeSide::Buy), 75);
+        assert_eq!(schedule.fee_bps(10_000_000_000, &TradeSide::Sell), 100);
+        assert_eq!(schedule.fee_bps(u64::MAX, &TradeSide::Sell), 50);
+        
+        // First tier must start at zero volume
+        let mut invalid = tiers.clone();
+        invalid[0].min_volume = 1;
+        assert!(validate_fee_tiers(&invalid).is_err());
+        
+        // Thresholds must increase
+        let mut invalid = tiers.clone();
+        invalid[2].min_volume = invalid[1].min_volume;
+        assert!(validate_fee_tiers(&invalid).is_err());
+        
+        // Rates are bound by the 10% cap
+        let mut invalid = tiers.clone();
+        invalid[1].sell_fee_bps = 1001;
+        assert!(validate_fee_tiers(&invalid).is_err());
+        
+        assert!(validate_fee_tiers(&[]).is_err());
+        
+        // Flat fee applies until the schedule is enabled
+        let mut global_state = sample_global_state();
+        let mut creator_profile = CreatorProfile {
+            creator: Pubkey::new_unique(),
+            launches_count: 1,
+            successful_launches: 0,
+            total_volume: 0,
+            verified: true,
+            reputation_score: 0,
+            reputation_adjustment: 0,
+            platform_fee_override_bps: None,
+            created_at: 0,
+            bump: 255,
+        };
+        assert_eq!(resolve_platform_fee_bps(&global_state, None, &creator_profile, 0, &TradeSide::Buy).unwrap(), 200);
+        
+        global_state.fee_schedule_enabled = true;
+        assert!(resolve_platform_fee_bps(&global_state, None, &creator_profile, 0, &TradeSide::Buy).is_err());
+        assert_eq!(
+            resolve_platform_fee_bps(&global_state, Some(&schedule), &creator_profile, 0, &TradeSide::Sell).unwrap(),
+            150
+        );
+        
+        // A creator override beats both the flat fee and the tiers
+        creator_profile.platform_fee_override_bps = Some(25);
+        assert_eq!(
+            resolve_platform_fee_bps(&global_state, Some(&schedule), &creator_profile, 0, &TradeSide::Sell).unwrap(),
+            25
+        );
+    }
+
+    #[test]
+    fn test_fee_override_decrease() {
+        let mut global_state = sample_global_state();
+        let mut creator_profile = CreatorProfile {
+            creator: Pubkey::new_unique(),
+            launches_count: 1,
+            successful_launches: 0,
+            total_volume: 0,
+            verified: true,
+            reputation_score: 0,
+            reputation_adjustment: 0,
+            platform_fee_override_bps: None,
+            created_at: 0,
+            bump: 255,
+        };
+        
+        // Flat fee: overrides at or below it apply immediately
+        assert!(check_fee_override_decrease(&global_state, None, &creator_profile, Some(150)).is_ok());
+        assert!(check_fee_override_decrease(&global_state, None, &creator_profile, Some(200)).is_ok());
+        assert!(check_fee_override_decrease(&global_state, None, &creator_profile, Some(201)).is_err());
+        assert!(check_fee_override_decrease(&global_state, None, &creator_profile, None).is_err());
+        
+        // With tiers enabled the override must not exceed the cheapest tier on either side
+        let schedule = FeeSchedule {
+            tiers: vec![
+                FeeTier { min_volume: 0, buy_fee_bps: 100, sell_fee_bps: 150 },
+                FeeTier { min_volume: 10_000_000_000, buy_fee_bps: 50, sell_fee_bps: 75 },
+            ],
+            bump: 255,
+        };
+        global_state.fee_schedule_enabled = true;
+        assert!(check_fee_override_decrease(&global_state, None, &creator_profile, Some(50)).is_err());
+        assert!(check_fee_override_decrease(&global_state, Some(&schedule), &creator_profile, Some(200)).is_err());
+        assert!(check_fee_override_decrease(&global_state, Some(&schedule), &creator_profile, Some(100)).is_err());
+        assert!(check_fee_override_decrease(&global_state, Some(&schedule), &creator_profile, Some(51)).is_err());
+        assert!(check_fee_override_decrease(&global_state, Some(&schedule), &creator_profile, Some(50)).is_ok());
+        
+        // An existing override is what every trade pays, tiers or not
+        creator_profile.platform_fee_override_bps = Some(25);
+        assert!(check_fee_override_decrease(&global_state, Some(&schedule), &creator_profile, Some(25)).is_ok());
+        assert!(check_fee_override_decrease(&global_state, Some(&schedule), &creator_profile, Some(26)).is_err());
+        assert!(check_fee_override_decrease(&global_state, Some(&schedule), &creator_profile, None).is_err());
+    }
+
+    #[test]
+    fn test_admin_roles() {
+        let mut global_state = sample_global_state();
+        let outsider = Pubkey::new_unique();
+        
+        // The authority holds every role; unset roles match nobody else
+        assert!(global_state.is_pauser(&global_state.authority));
+        assert!(global_state.is_fee_manager(&global_state.authority));
+        assert!(global_state.is_verifier(&global_state.authority));
+        assert!(global_state.is_fee_manager(&global_state.fee_manager));
+        assert!(!global_state.is_pauser(&global_state.fee_manager));
+        assert!(!global_state.is_verifier(&global_state.fee_manager));
+        assert!(!global_state.is_verifier(&outsider));
+        
+        // Granting a role does not extend to the other roles
+        global_state.verifier = outsider;
+        assert!(global_state.is_verifier(&outsider));
+        assert!(!global_state.is_pauser(&outsider));
+        assert!(!global_state.is_fee_manager(&outsider));
+    }
+
+    #[test]
+    fn test_admin_action_validation() {
+        assert!(validate_admin_action(&AdminAction::SetPlatformFee { platform_fee_bps: 250 }).is_ok());
+        assert!(validate_admin_action(&AdminAction::SetPlatformFee { platform_fee_bps: 1001 }).is_err());
+        
+        let tiers = vec![FeeTier { min_volume: 0, buy_fee_bps: 100, sell_fee_bps: 100 }];
+        assert!(validate_admin_action(&AdminAction::SetFeeTiers { tiers }).is_ok());
+        assert!(validate_admin_action(&AdminAction::SetFeeTiers { tiers: vec![] }).is_err());
+        
+        let new_authority = Pubkey::new_unique();
+        assert!(validate_admin_action(&AdminAction::TransferAuthority { new_authority }).is_ok());
+        assert!(validate_admin_action(&AdminAction::TransferAuthority { new_authority: Pubkey::default() }).is_err());
+        
+        // A full tier list must fit in the pending action account
+        let max_tiers = vec![FeeTier { min_volume: 0, buy_fee_bps: 0, sell_fee_bps: 0 }; FeeSchedule::MAX_TIERS];
+        let action = AdminAction::SetFeeTiers { tiers: max_tiers };
+        assert_eq!(action.try_to_vec().unwrap().len(), AdminAction::MAX_LEN);
+    }
+
     #[test]
     fn test_slippage_validation() {
         let expected_amount = 1000u64;
@@ -220,6 +975,95 @@ mod tests {
         assert!(result.is_err()); // No slippage tolerance, should fail
     }
 
+    #[test]
+    fn test_curve_vault_consistency() {
+        let allocation = 500000u64;
+        
+        // Fresh launch: everything still in the vault
+        assert!(check_curve_vault_balance(allocation, 0, allocation).is_ok());
+        
+        // After some buys the vault and supply sold still add up
+        assert!(check_curve_vault_balance(420000, 80000, allocation).is_ok());
+        
+        // Tokens leaked out of the vault without being accounted for
+        assert!(check_curve_vault_balance(410000, 80000, allocation).is_err());
+    }
+
+    #[test]
+    fn test_vesting_schedule() {
+        let total = 1_000_000u64;
+        let start = 1000i64;
+        let cliff = 100i64;
+        let duration = 1000i64;
+        
+        // Before the cliff nothing is vested
+        assert_eq!(calculate_vested_amount(total, start, cliff, duration, 1050).unwrap(), 0);
+        
+        // At the cliff the linear portion unlocks: 1M * 100 / 1000
+        assert_eq!(calculate_vested_amount(total, start, cliff, duration, 1100).unwrap(), 100_000);
+        
+        // Halfway through
+        assert_eq!(calculate_vested_amount(total, start, cliff, duration, 1500).unwrap(), 500_000);
+        
+        // Fully vested after the duration
+        assert_eq!(calculate_vested_amount(total, start, cliff, duration, 5000).unwrap(), total);
+    }
+
+    #[test]
+    fn test_reputation_score() {
+        // New creator with no launches
+        assert_eq!(calculate_reputation_score(0, 0, false, 0), 0);
+        
+        // Verified bonus applies even before any launches
+        assert_eq!(calculate_reputation_score(0, 0, true, 0), 100);
+        
+        // 2 of 4 launches graduated: 700 * 2 / 4 + 2 * 20 = 390
+        assert_eq!(calculate_reputation_score(4, 2, false, 0), 390);
+        
+        // Experience is capped: 700 + 200 + 100 = 1000
+        assert_eq!(calculate_reputation_score(20, 20, true, 0), 1000);
+        
+        // Admin adjustments are clamped to the valid range
+        assert_eq!(calculate_reputation_score(20, 20, true, 500), 1000);
+        assert_eq!(calculate_reputation_score(4, 2, false, -500), 0);
+        assert_eq!(calculate_reputation_score(4, 2, false, -90), 300);
+    }
+
+    #[test]
+    fn test_price_history_candles() {
+        let mut history: Box<PriceHistory> = Box::new(bytemuck::Zeroable::zeroed());
+        
+        // First trade opens a candle aligned to the minute
+        history.record_trade(1_000_030, 100, 110, 5000, 50);
+        assert_eq!(history.count, 1);
+        assert_eq!(history.candles[0].start_time, 1_000_020);
+        assert_eq!(history.candles[0].open, 100);
+        assert_eq!(history.candles[0].close, 110);
+        
+        // Trades within the same minute update the candle
+        history.record_trade(1_000_050, 110, 95, 2000, 20);
+        let candle = history.candles[0];
+        assert_eq!(history.count, 1);
+        assert_eq!(candle.high, 110);
+        assert_eq!(candle.low, 95);
+        assert_eq!(candle.close, 95);
+        assert_eq!(candle.sol_volume, 7000);
+        assert_eq!(candle.token_volume, 70);
+        
+        // Next minute starts a new candle
+        history.record_trade(1_000_090, 95, 120, 1000, 10);
+        assert_eq!(history.count, 2);
+        assert_eq!(history.head, 1);
+        assert_eq!(history.candles[1].open, 95);
+        
+        // Ring buffer wraps once full
+        for i in 0..PriceHistory::CAPACITY as i64 {
+            history.record_trade(2_000_000 + i * 60, 100, 100, 1, 1);
+        }
+        assert_eq!(history.count, PriceHistory::CAPACITY as u32);
+        assert_eq!(history.head, 1);
+    }
+
     #[test]
     fn test_parameter_validation() {
         let valid_params = CreateLaunchpadParams {
@@ -228,6 +1072,7 @@ mod tests {
             uri: "https://example.com/metadata.json".to_string(),
             decimals: 9,
             total_supply: 1000000,
+            lp_reserve: 500000,
             curve_type: CurveType::Linear,
             curve_params: CurveParams {
                 base_price: 1000,
@@ -243,6 +1088,14 @@ mod tests {
                 time_limit: None,
                 custom_logic: None,
             },
+            vesting: None,
+            initial_buy_sol: None,
+            anti_snipe: None,
+            max_tokens_per_wallet: None,
+            max_sol_per_tx: None,
+            presale: None,
+            trading_starts_at: None,
+            failure_deadline: None,
         };
         
         // Valid parameters should pass
@@ -262,5 +1115,49 @@ mod tests {
         let mut invalid_params = valid_params.clone();
         invalid_params.decimals = 15; // > 9 limit
         assert!(validate_create_params(&invalid_params).is_err());
+        
+        // Test supply allocations that don't add up to total supply
+        let mut invalid_params = valid_params.clone();
+        invalid_params.lp_reserve = 400000; // 500k + 400k != 1M
+        assert!(validate_create_params(&invalid_params).is_err());
+        
+        // Test zero initial buy
+        let mut invalid_params = valid_params.clone();
+        invalid_params.initial_buy_sol = Some(0);
+        assert!(validate_create_params(&invalid_params).is_err());
+        
+        // Test zero trade limits
+        let mut invalid_params = valid_params.clone();
+        invalid_params.max_tokens_per_wallet = Some(0);
+        assert!(validate_create_params(&invalid_params).is_err());
+        
+        // Test sniper fee above the 50% cap
+        let mut invalid_params = valid_params.clone();
+        invalid_params.anti_snipe = Some(AntiSnipeParams {
+            start_delay_seconds: 0,
+            max_buy_sol: 0,
+            max_buy_window_seconds: 0,
+            sniper_fee_bps: 6000,
+            decay_seconds: 60,
+        });
+        assert!(validate_create_params(&invalid_params).is_err());
+        
+        // Vesting allocation counts toward total supply
+        let mut vesting_params = valid_params.clone();
+        vesting_params.lp_reserve = 400000;
+        vesting_params.vesting = Some(VestingParams {
+            amount: 100000,
+            cliff_seconds: 86400,
+            duration_seconds: 86400 * 30,
+            start: VestingStart::Graduation,
+        });
+        assert!(validate_create_params(&vesting_params).is_ok());
+        
+        // Cliff longer than the vesting duration is rejected
+        let mut invalid_params = vesting_params.clone();
+        if let Some(vesting) = invalid_params.vesting.as_mut() {
+            vesting.cliff_seconds = 86400 * 31;
+        }
+        assert!(validate_create_params(&invalid_params).is_err());
     }
 }
\ No newline at end of file
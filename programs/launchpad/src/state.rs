@@ -10,13 +10,39 @@ pub struct GlobalState {
     pub platform_fee_bps: u16,    // Platform fee in basis points (0-1000 = 0%-10%)
     pub fee_vault: Pubkey,         // Platform fee collection vault
     pub paused: bool,              // Emergency pause state
+    pub paused_at: i64,            // Timestamp of the last pause change
+    pub pause_reason: u16,         // Reason code for the current pause (0 = none)
+    pub sell_only: bool,           // Platform-wide sell-only mode
     pub upgrade_authority: Pubkey, // Program upgrade authority
+    pub verified_creators_only: bool, // Restrict launch creation to verified creators
+    pub creation_mode: CreationMode,  // Curated (admin co-signs) or permissionless
+    pub creation_fee_lamports: u64,   // Fee charged per launch, sent to the fee vault
+    pub creation_deposit_lamports: u64, // Refundable anti-spam deposit per launch
+    pub referral_share_bps: u16,   // Share of the platform fee paid to referrers
+    pub fee_schedule_enabled: bool, // Trades use FeeSchedule tiers instead of the flat fee
+    pub pauser: Pubkey,            // Hot key that can only pause
+    pub fee_manager: Pubkey,       // Can withdraw fees to the treasury
+    pub verifier: Pubkey,          // Can verify creators
+    pub treasury: Pubkey,          // Owner of the token account fees are withdrawn to
     pub bump: u8,                  // PDA bump
 }
 
 impl GlobalState {
-    pub const LEN: usize = 32 + 2 + 32 + 1 + 32 + 1;
+    pub const LEN: usize = 32 + 2 + 32 + 1 + 8 + 2 + 1 + 32 + 1 + 1 + 8 + 8 + 2 + 1 + 32 + 32 + 32 + 32 + 1;
     pub const SEEDS: &'static [u8] = b"global_state";
+    
+    // The main authority holds every role
+    pub fn is_pauser(&self, key: &Pubkey) -> bool {
+        *key == self.authority || *key == self.pauser
+    }
+    
+    pub fn is_fee_manager(&self, key: &Pubkey) -> bool {
+        *key == self.authority || *key == self.fee_manager
+    }
+    
+    pub fn is_verifier(&self, key: &Pubkey) -> bool {
+        *key == self.authority || *key == self.verifier
+    }
 }
 
 // ============================================================================
@@ -29,19 +55,34 @@ pub struct LaunchpadState {
     pub creator: Pubkey,                 // Creator authority
     pub sol_vault: Pubkey,               // SOL collection vault
     pub token_vault: Pubkey,             // Token vault for initial LP
+    pub curve_vault: Pubkey,             // Token vault holding the curve allocation
     pub bonding_curve: Pubkey,           // Associated bonding curve PDA
     pub status: LaunchpadStatus,         // Current status
+    pub resume_status: LaunchpadStatus,  // Status restored when unpaused
     pub creator_fee_bps: u16,            // Creator fee in basis points
     pub total_supply: u64,               // Total token supply
+    pub lp_reserve: u64,                 // Tokens reserved for LP provision
+    pub vesting_amount: u64,             // Tokens escrowed for creator vesting
+    pub creator_initial_buy_sol: u64,    // SOL spent on the creator's initial buy
+    pub creator_initial_buy_tokens: u64, // Tokens received from the creator's initial buy
+    pub creator_position_seeded: bool,   // Initial buy already counted in the creator's TraderPosition
+    pub creator_deposit: u64,            // Anti-spam deposit held until graduation or failure
+    pub anti_snipe: Option<AntiSnipeParams>, // Launch window protections
+    pub max_tokens_per_wallet: Option<u64>, // Cap on cumulative tokens bought per wallet
+    pub max_sol_per_tx: Option<u64>,     // Cap on SOL spent per buy
+    pub presale: Option<PresaleConfig>,  // Allowlisted presale before public trading
+    pub trading_starts_at: Option<i64>,  // Scheduled start of curve trading
+    pub failure_deadline: Option<i64>,   // Launch fails if not graduatable by then
     pub graduation_criteria: GraduationCriteria, // When to graduate
     pub meteora_pool: Option<Pubkey>,    // Meteora pool address (post-graduation)
+    pub price_history: Option<Pubkey>,   // Candle history, required on every trade once set
     pub created_at: i64,                 // Creation timestamp
     pub graduated_at: Option<i64>,       // Graduation timestamp
     pub bump: u8,                        // PDA bump
 }
 
 impl LaunchpadState {
-    pub const LEN: usize = 32 + 32 + 32 + 32 + 32 + 1 + 2 + 8 + 64 + 33 + 8 + 9 + 1;
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 32 + 32 + 1 + 1 + 2 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 35 + 9 + 9 + 58 + 9 + 9 + 64 + 33 + 33 + 8 + 9 + 1;
     pub const SEEDS: &'static [u8] = b"launchpad";
 }
 
@@ -59,12 +100,15 @@ pub struct BondingCurveState {
     pub virtual_sol_reserves: u64,  // Virtual reserves for pricing
     pub virtual_token_reserves: u64,// Virtual token reserves
     pub fee_collected: u64,         // Total fees collected
+    pub total_volume: u64,          // Cumulative SOL traded on this curve
     pub last_price: u64,            // Last calculated price (cached)
+    pub price_cumulative: u128,     // Sum of last_price * seconds held, for TWAP
+    pub last_price_update: i64,     // Timestamp price_cumulative was last advanced
     pub bump: u8,                   // PDA bump
 }
 
 impl BondingCurveState {
-    pub const LEN: usize = 32 + 1 + 64 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 32 + 1 + 64 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 16 + 8 + 1;
     pub const SEEDS: &'static [u8] = b"bonding_curve";
 }
 
@@ -72,14 +116,53 @@ impl BondingCurveState {
 // Enums and Structs
 // ============================================================================
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CreationMode {
+    Curated,        // Platform authority must co-sign every launch
+    Permissionless, // Anyone can launch, paying the creation fee
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreationConfigParams {
+    pub creation_mode: CreationMode,      // Curated or permissionless creation
+    pub creation_fee_lamports: u64,       // Fee charged per launch
+    pub creation_deposit_lamports: u64,   // Refundable deposit per launch
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum LaunchpadStatus {
     Active,
     Graduated,
     Paused,
+    SellOnly,    // Buys rejected, holders can still sell
+    Failed,      // Missed its failure deadline; terminal, exit-only
+}
+
+impl LaunchpadStatus {
+    pub fn allows_buys(&self) -> bool {
+        *self == LaunchpadStatus::Active
+    }
+    
+    // Holders of a failed launch can still sell back to the curve
+    pub fn allows_sells(&self) -> bool {
+        matches!(self, LaunchpadStatus::Active | LaunchpadStatus::SellOnly | LaunchpadStatus::Failed)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum AdminRole {
+    Pauser,
+    FeeManager,
+    Verifier,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum CurveType {
     Linear,
     Exponential,
@@ -101,7 +184,7 @@ pub struct CurveParams {
 pub struct GraduationCriteria {
     pub min_sol_raised: Option<u64>,      // Minimum SOL to raise
     pub min_supply_sold: Option<u64>,     // Minimum tokens to sell
-    pub time_limit: Option<i64>,          // Unix timestamp deadline
+    pub time_limit: Option<i64>,          // Earliest graduation time (Unix timestamp)
     pub custom_logic: Option<Pubkey>,     // Future: custom graduation program
 }
 
@@ -112,10 +195,50 @@ pub struct CreateLaunchpadParams {
     pub uri: String,                      // Metadata URI (max 200 chars)
     pub decimals: u8,                     // Token decimals
     pub total_supply: u64,                // Total token supply
+    pub lp_reserve: u64,                  // Tokens reserved for LP provision
     pub curve_type: CurveType,            // Bonding curve type
     pub curve_params: CurveParams,        // Curve parameters
     pub creator_fee_bps: u16,             // Creator fee (0-500 = 0%-5%)
     pub graduation_criteria: GraduationCriteria, // Graduation rules
+    pub vesting: Option<VestingParams>,   // Optional creator vesting allocation
+    pub initial_buy_sol: Option<u64>,     // Optional creator buy executed at creation
+    pub anti_snipe: Option<AntiSnipeParams>, // Optional launch window protections
+    pub max_tokens_per_wallet: Option<u64>, // Optional cap on tokens bought per wallet
+    pub max_sol_per_tx: Option<u64>,      // Optional cap on SOL spent per buy
+    pub presale: Option<PresaleConfig>,   // Optional allowlisted presale phase
+    pub trading_starts_at: Option<i64>,   // Optional scheduled trading start (Unix timestamp)
+    pub failure_deadline: Option<i64>,    // Optional deadline after which the deposit can be forfeited
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PresaleConfig {
+    pub merkle_root: [u8; 32],            // Root of (wallet, max allocation) leaves
+    pub start_time: i64,                  // Presale opens
+    pub end_time: i64,                    // Presale closes, public trading opens
+    pub presale_price: Option<u64>,       // Fixed lamports per token (None = curve price)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AntiSnipeParams {
+    pub start_delay_seconds: i64,         // Buys rejected until trading start + delay
+    pub max_buy_sol: u64,                 // Max SOL per buy during the limit window (0 = none)
+    pub max_buy_window_seconds: i64,      // How long the per-buy cap applies after start
+    pub sniper_fee_bps: u16,              // Total fee at launch start, decays to normal
+    pub decay_seconds: i64,               // Time for the sniper fee to decay to normal
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VestingStart {
+    Creation,   // Accrues from creation, claimable after graduation
+    Graduation,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VestingParams {
+    pub amount: u64,                      // Tokens allocated to the creator
+    pub cliff_seconds: i64,               // Nothing unlocks before the cliff
+    pub duration_seconds: i64,            // Full unlock after this long
+    pub start: VestingStart,              // When the schedule starts counting
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -143,11 +266,308 @@ pub struct CreatorProfile {
     pub total_volume: u64,       // Total trading volume
     pub verified: bool,          // Verified creator status
     pub reputation_score: u16,   // Reputation (0-1000)
+    pub reputation_adjustment: i16, // Admin adjustment applied on top of the formula
+    pub platform_fee_override_bps: Option<u16>, // Negotiated platform fee on this creator's launches
     pub created_at: i64,         // Profile creation time
     pub bump: u8,                // PDA bump
 }
 
 impl CreatorProfile {
-    pub const LEN: usize = 32 + 4 + 4 + 8 + 1 + 2 + 8 + 1;
+    pub const LEN: usize = 32 + 4 + 4 + 8 + 1 + 2 + 2 + 3 + 8 + 1;
     pub const SEEDS: &'static [u8] = b"creator_profile";
-}
\ No newline at end of file
+    
+    pub fn initialize(&mut self, creator: Pubkey, bump: u8, now: i64) {
+        self.creator = creator;
+        self.launches_count = 0;
+        self.successful_launches = 0;
+        self.total_volume = 0;
+        self.verified = false;
+        self.reputation_score = 0;
+        self.reputation_adjustment = 0;
+        self.platform_fee_override_bps = None;
+        self.created_at = now;
+        self.bump = bump;
+    }
+}
+
+// ============================================================================
+// Vesting Schedule Account
+// ============================================================================
+
+#[account]
+pub struct VestingSchedule {
+    pub launchpad: Pubkey,       // Parent launchpad
+    pub beneficiary: Pubkey,     // Creator receiving the vested tokens
+    pub vault: Pubkey,           // Escrow token account owned by this PDA
+    pub total_amount: u64,       // Total tokens under vesting
+    pub claimed_amount: u64,     // Tokens already claimed
+    pub cliff_seconds: i64,      // Cliff measured from start_time
+    pub duration_seconds: i64,   // Linear unlock duration from start_time
+    pub start: VestingStart,     // Creation or graduation
+    pub start_time: Option<i64>, // Set once the schedule has started
+    pub bump: u8,                // PDA bump
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 9 + 1;
+    pub const SEEDS: &'static [u8] = b"vesting_schedule";
+}
+
+// ============================================================================
+// Trader Position Account
+// ============================================================================
+
+#[account]
+pub struct TraderPosition {
+    pub launchpad: Pubkey,       // Parent launchpad
+    pub trader: Pubkey,          // Trader wallet
+    pub tokens_bought: u64,      // Cumulative tokens bought via the curve
+    pub tokens_held: u64,        // Tokens currently held via the curve
+    pub cost_basis: u64,         // SOL cost of tokens currently held
+    pub total_sol_spent: u64,    // Cumulative SOL spent on buys
+    pub total_sol_received: u64, // Cumulative SOL received from sells
+    pub realized_pnl: i64,       // Realized PnL in lamports (average cost)
+    pub trade_count: u32,        // Number of trades
+    pub first_trade_at: i64,     // First trade timestamp
+    pub last_trade_at: i64,      // Most recent trade timestamp
+    pub bump: u8,                // PDA bump
+}
+
+impl TraderPosition {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 8 + 8 + 1;
+    pub const SEEDS: &'static [u8] = b"trader_position";
+    
+    pub fn average_entry_price(&self) -> u64 {
+        if self.tokens_held == 0 {
+            return 0;
+        }
+        self.cost_basis / self.tokens_held
+    }
+    
+    pub fn record_buy(&mut self, timestamp: i64, sol_spent: u64, tokens: u64) {
+        if self.trade_count == 0 {
+            self.first_trade_at = timestamp;
+        }
+        self.tokens_held = self.tokens_held.saturating_add(tokens);
+        self.cost_basis = self.cost_basis.saturating_add(sol_spent);
+        self.total_sol_spent = self.total_sol_spent.saturating_add(sol_spent);
+        self.trade_count = self.trade_count.saturating_add(1);
+        self.last_trade_at = timestamp;
+    }
+    
+    pub fn record_sell(&mut self, timestamp: i64, sol_received: u64, tokens: u64) {
+        // Only the portion backed by curve buys realizes PnL; tokens acquired
+        // elsewhere have no known cost basis
+        let tokens_closed = tokens.min(self.tokens_held);
+        if tokens_closed > 0 {
+            let cost_closed = (self.cost_basis as u128 * tokens_closed as u128
+                / self.tokens_held as u128) as u64;
+            let proceeds_closed = (sol_received as u128 * tokens_closed as u128
+                / tokens as u128) as u64;
+            
+            self.realized_pnl = self.realized_pnl
+                .saturating_add(proceeds_closed as i64)
+                .saturating_sub(cost_closed as i64);
+            self.cost_basis -= cost_closed;
+            self.tokens_held -= tokens_closed;
+        }
+        
+        if self.trade_count == 0 {
+            self.first_trade_at = timestamp;
+        }
+        self.total_sol_received = self.total_sol_received.saturating_add(sol_received);
+        self.trade_count = self.trade_count.saturating_add(1);
+        self.last_trade_at = timestamp;
+    }
+}
+
+// ============================================================================
+// Fee Schedule Account
+// ============================================================================
+
+#[account]
+pub struct FeeSchedule {
+    pub tiers: Vec<FeeTier>,     // Tiers sorted by ascending volume threshold
+    pub bump: u8,                // PDA bump
+}
+
+impl FeeSchedule {
+    pub const MAX_TIERS: usize = 8;
+    pub const LEN: usize = 4 + FeeTier::LEN * Self::MAX_TIERS + 1;
+    pub const SEEDS: &'static [u8] = b"fee_schedule";
+    
+    pub fn fee_bps(&self, launch_volume: u64, side: &TradeSide) -> u16 {
+        // Highest tier whose threshold the launch has reached
+        let tier = self.tiers.iter()
+            .rev()
+            .find(|tier| launch_volume >= tier.min_volume)
+            .or(self.tiers.first());
+        
+        match (tier, side) {
+            (Some(tier), TradeSide::Buy) => tier.buy_fee_bps,
+            (Some(tier), TradeSide::Sell) => tier.sell_fee_bps,
+            (None, _) => 0,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FeeTier {
+    pub min_volume: u64,         // Per-launch cumulative SOL volume where the tier starts
+    pub buy_fee_bps: u16,        // Platform fee on buys
+    pub sell_fee_bps: u16,       // Platform fee on sells
+}
+
+impl FeeTier {
+    pub const LEN: usize = 8 + 2 + 2;
+}
+
+// ============================================================================
+// Pending Admin Action Account
+// ============================================================================
+
+#[account]
+pub struct PendingAdminAction {
+    pub action_id: u64,          // Caller-chosen identifier (part of the PDA seeds)
+    pub action: AdminAction,     // Queued GlobalState mutation
+    pub proposer: Pubkey,        // Authority that queued the action
+    pub queued_at: i64,          // Queue timestamp
+    pub execute_after: i64,      // Earliest execution timestamp
+    pub bump: u8,                // PDA bump
+}
+
+impl PendingAdminAction {
+    pub const LEN: usize = 8 + AdminAction::MAX_LEN + 32 + 8 + 8 + 1;
+    pub const SEEDS: &'static [u8] = b"pending_admin_action";
+    pub const TIMELOCK_SECONDS: i64 = 48 * 60 * 60; // 48 hours
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum AdminAction {
+    SetPlatformFee { platform_fee_bps: u16 },
+    SetFeeTiers { tiers: Vec<FeeTier> },
+    TransferAuthority { new_authority: Pubkey },
+    SetTreasury { treasury: Pubkey },
+    SetCreatorFeeOverride { creator: Pubkey, platform_fee_bps: Option<u16> },
+}
+
+impl AdminAction {
+    // Largest variant: tag + vec length + max tiers
+    pub const MAX_LEN: usize = 1 + 4 + FeeTier::LEN * FeeSchedule::MAX_TIERS;
+}
+
+// ============================================================================
+// Referral Account
+// ============================================================================
+
+#[account]
+pub struct ReferralAccount {
+    pub referrer: Pubkey,        // Referrer wallet
+    pub total_earned: u64,       // Cumulative referral fees earned
+    pub total_claimed: u64,      // Cumulative referral fees claimed
+    pub referred_trades: u64,    // Trades carrying this referral
+    pub bump: u8,                // PDA bump
+}
+
+impl ReferralAccount {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 1;
+    pub const SEEDS: &'static [u8] = b"referral";
+}
+
+// ============================================================================
+// Presale Receipt Account
+// ============================================================================
+
+#[account]
+pub struct PresaleReceipt {
+    pub launchpad: Pubkey,       // Parent launchpad
+    pub buyer: Pubkey,           // Allowlisted wallet
+    pub sol_spent: u64,          // SOL spent against the allocation
+    pub tokens_received: u64,    // Tokens received in the presale
+    pub bump: u8,                // PDA bump
+}
+
+impl PresaleReceipt {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 1;
+    pub const SEEDS: &'static [u8] = b"presale_receipt";
+}
+
+// ============================================================================
+// Price History Account (Zero-Copy OHLCV Ring Buffer)
+// ============================================================================
+
+#[zero_copy]
+pub struct Candle {
+    pub start_time: i64,         // Candle open time (aligned to CANDLE_SECONDS)
+    pub open: u64,               // Price before the first trade in the candle
+    pub high: u64,               // Highest price seen
+    pub low: u64,                // Lowest price seen
+    pub close: u64,              // Price after the last trade in the candle
+    pub sol_volume: u64,         // SOL traded (gross)
+    pub token_volume: u64,       // Tokens traded
+}
+
+impl Candle {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8;
+}
+
+#[account(zero_copy)]
+pub struct PriceHistory {
+    pub launchpad: Pubkey,       // Parent launchpad
+    pub head: u32,               // Index of the most recent candle
+    pub count: u32,              // Number of candles written (max CAPACITY)
+    pub bump: u8,                // PDA bump
+    pub padding: [u8; 7],        // Alignment padding
+    pub candles: [Candle; 180],  // Ring buffer of 1-minute candles
+}
+
+impl PriceHistory {
+    pub const CAPACITY: usize = 180;     // 3 hours of 1-minute candles
+    pub const CANDLE_SECONDS: i64 = 60;
+    pub const LEN: usize = 32 + 4 + 4 + 1 + 7 + Candle::LEN * Self::CAPACITY;
+    pub const SEEDS: &'static [u8] = b"price_history";
+    
+    pub fn initialize(&mut self, launchpad: Pubkey, bump: u8) {
+        self.launchpad = launchpad;
+        self.head = 0;
+        self.count = 0;
+        self.bump = bump;
+    }
+    
+    pub fn record_trade(
+        &mut self,
+        timestamp: i64,
+        price_before: u64,
+        price_after: u64,
+        sol_volume: u64,
+        token_volume: u64,
+    ) {
+        let start_time = timestamp - timestamp.rem_euclid(Self::CANDLE_SECONDS);
+        let current = &self.candles[self.head as usize];
+        
+        // Start a new candle when the trade falls into a later time bucket
+        if self.count == 0 || start_time > current.start_time {
+            if self.count > 0 {
+                self.head = (self.head + 1) % Self::CAPACITY as u32;
+            }
+            self.count = (self.count + 1).min(Self::CAPACITY as u32);
+            self.candles[self.head as usize] = Candle {
+                start_time,
+                open: price_before,
+                high: price_before.max(price_after),
+                low: price_before.min(price_after),
+                close: price_after,
+                sol_volume,
+                token_volume,
+            };
+            return;
+        }
+        
+        let candle = &mut self.candles[self.head as usize];
+        candle.high = candle.high.max(price_after);
+        candle.low = candle.low.min(price_after);
+        candle.close = price_after;
+        candle.sol_volume = candle.sol_volume.saturating_add(sol_volume);
+        candle.token_volume = candle.token_volume.saturating_add(token_volume);
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 use crate::error::LaunchpadError;
 use crate::state::*;
 
@@ -45,6 +46,78 @@ pub fn find_token_vault_pda(launchpad: &Pubkey, program_id: &Pubkey) -> (Pubkey,
     )
 }
 
+pub fn find_vesting_schedule_pda(launchpad: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VestingSchedule::SEEDS, launchpad.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_vesting_vault_pda(launchpad: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"vesting_vault", launchpad.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_trader_position_pda(
+    launchpad: &Pubkey,
+    trader: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TraderPosition::SEEDS, launchpad.as_ref(), trader.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_presale_receipt_pda(
+    launchpad: &Pubkey,
+    buyer: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PresaleReceipt::SEEDS, launchpad.as_ref(), buyer.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_fee_schedule_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FeeSchedule::SEEDS], program_id)
+}
+
+pub fn find_pending_admin_action_pda(action_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PendingAdminAction::SEEDS, &action_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+pub fn find_referral_account_pda(referrer: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ReferralAccount::SEEDS, referrer.as_ref()], program_id)
+}
+
+pub fn find_price_history_pda(launchpad: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PriceHistory::SEEDS, launchpad.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_creator_fee_vault_pda(launchpad: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"creator_fee_vault", launchpad.as_ref()],
+        program_id,
+    )
+}
+
+pub fn find_curve_vault_pda(launchpad: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"curve_vault", launchpad.as_ref()],
+        program_id,
+    )
+}
+
 // ============================================================================
 // Fixed-Point Arithmetic Utilities (Q32.32)
 // ============================================================================
@@ -202,6 +275,176 @@ pub fn calculate_exponential_cost(
         .ok_or(LaunchpadError::ArithmeticOverflow.into())
 }
 
+pub fn calculate_curve_cost(
+    supply_start: u64,
+    supply_end: u64,
+    curve_type: &CurveType,
+    curve_params: &CurveParams,
+) -> Result<u64> {
+    match curve_type {
+        CurveType::Linear => {
+            calculate_linear_cost(
+                supply_start,
+                supply_end,
+                curve_params.base_price,
+                curve_params.slope,
+            )
+        }
+        CurveType::Exponential => {
+            calculate_exponential_cost(
+                supply_start,
+                supply_end,
+                curve_params.base_price,
+                curve_params.slope,
+                curve_params.step,
+            )
+        }
+        CurveType::Custom => {
+            Err(LaunchpadError::InvalidCurveParams.into())
+        }
+    }
+}
+
+pub fn calculate_tokens_for_sol_amount(
+    sol_amount: u64,
+    current_supply: u64,
+    curve_type: &CurveType,
+    curve_params: &CurveParams,
+) -> Result<u64> {
+    match curve_type {
+        CurveType::Linear => {
+            // For linear curve: P(S) = base_price + slope * S
+            // We need to solve: sol_amount = integral from current_supply to (current_supply + tokens)
+            // This is a quadratic equation: slope/2 * tokens^2 + (base_price + slope*current_supply) * tokens - sol_amount = 0
+            
+            let base_price = curve_params.base_price;
+            let slope = curve_params.slope;
+            
+            if slope == 0 {
+                // Simple case: constant price
+                return Ok(sol_amount / base_price);
+            }
+            
+            let current_price = calculate_linear_price(current_supply, base_price, slope)?;
+            let a = slope / 2; // coefficient of tokens^2
+            let b = current_price; // coefficient of tokens
+            let c = sol_amount; // constant term (negated)
+            
+            // Quadratic formula: tokens = (-b + sqrt(b^2 + 4*a*c)) / (2*a)
+            let discriminant = b.checked_mul(b)
+                .ok_or(LaunchpadError::ArithmeticOverflow)?
+                .checked_add(4u64.checked_mul(a)
+                    .ok_or(LaunchpadError::ArithmeticOverflow)?
+                    .checked_mul(c)
+                    .ok_or(LaunchpadError::ArithmeticOverflow)?)
+                .ok_or(LaunchpadError::ArithmeticOverflow)?;
+            
+            let sqrt_discriminant = (discriminant as f64).sqrt() as u64;
+            let tokens = sqrt_discriminant.checked_sub(b)
+                .ok_or(LaunchpadError::ArithmeticUnderflow)?
+                .checked_div(2 * a)
+                .ok_or(LaunchpadError::DivisionByZero)?;
+                
+            Ok(tokens)
+        }
+        CurveType::Exponential => {
+            // For exponential curves, we use iterative approximation
+            // This is simplified - a more sophisticated implementation would use binary search
+            let mut tokens = 0u64;
+            let mut accumulated_cost = 0u64;
+            let step_size = 1000; // tokens per iteration
+            
+            while accumulated_cost < sol_amount && tokens < curve_params.max_supply {
+                let next_tokens = tokens + step_size;
+                let step_cost = calculate_exponential_cost(
+                    current_supply + tokens,
+                    current_supply + next_tokens,
+                    curve_params.base_price,
+                    curve_params.slope, // multiplier
+                    curve_params.step,
+                )?;
+                
+                if accumulated_cost + step_cost <= sol_amount {
+                    tokens = next_tokens;
+                    accumulated_cost += step_cost;
+                } else {
+                    break;
+                }
+            }
+            
+            Ok(tokens)
+        }
+        CurveType::Custom => {
+            // Future implementation
+            Err(LaunchpadError::InvalidCurveParams.into())
+        }
+    }
+}
+
+pub fn calculate_current_price(
+    supply: u64,
+    curve_type: &CurveType,
+    curve_params: &CurveParams,
+) -> Result<u64> {
+    match curve_type {
+        CurveType::Linear => {
+            calculate_linear_price(supply, curve_params.base_price, curve_params.slope)
+        }
+        CurveType::Exponential => {
+            calculate_exponential_price(
+                supply,
+                curve_params.base_price,
+                curve_params.slope,
+                curve_params.step,
+            )
+        }
+        CurveType::Custom => {
+            Err(LaunchpadError::InvalidCurveParams.into())
+        }
+    }
+}
+
+// ============================================================================
+// Time-Weighted Average Price
+// ============================================================================
+
+pub fn current_price_cumulative(curve_state: &BondingCurveState, current_time: i64) -> Result<u128> {
+    // Extrapolate the accumulator to now using the price held since the last update
+    let elapsed = current_time.saturating_sub(curve_state.last_price_update).max(0) as u128;
+    let accrued = (curve_state.last_price as u128)
+        .checked_mul(elapsed)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    curve_state.price_cumulative
+        .checked_add(accrued)
+        .ok_or(LaunchpadError::ArithmeticOverflow.into())
+}
+
+pub fn update_price_accumulator(curve_state: &mut BondingCurveState, current_time: i64) -> Result<()> {
+    // Must be called before last_price changes so the old price is credited
+    curve_state.price_cumulative = current_price_cumulative(curve_state, current_time)?;
+    curve_state.last_price_update = current_time.max(curve_state.last_price_update);
+    Ok(())
+}
+
+pub fn calculate_twap(
+    cumulative_start: u128,
+    time_start: i64,
+    cumulative_end: u128,
+    time_end: i64,
+) -> Result<u64> {
+    // TWAP = (cumulative_end - cumulative_start) / (time_end - time_start)
+    if time_end <= time_start {
+        return Err(LaunchpadError::DivisionByZero.into());
+    }
+    
+    let delta = cumulative_end.checked_sub(cumulative_start)
+        .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+    let twap = delta / (time_end - time_start) as u128;
+    
+    u64::try_from(twap).map_err(|_| LaunchpadError::ArithmeticOverflow.into())
+}
+
 // ============================================================================
 // Fee Calculation Utilities
 // ============================================================================
@@ -221,12 +464,368 @@ pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
         .ok_or(LaunchpadError::DivisionByZero.into())
 }
 
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1000; // 10%
+
+pub fn validate_fee_tiers(tiers: &[FeeTier]) -> Result<()> {
+    if tiers.is_empty() || tiers.len() > FeeSchedule::MAX_TIERS {
+        return Err(LaunchpadError::InvalidFeeTiers.into());
+    }
+    
+    // The first tier must apply from the first trade
+    if tiers[0].min_volume != 0 {
+        return Err(LaunchpadError::InvalidFeeTiers.into());
+    }
+    
+    // Thresholds must be strictly increasing
+    if tiers.windows(2).any(|pair| pair[1].min_volume <= pair[0].min_volume) {
+        return Err(LaunchpadError::InvalidFeeTiers.into());
+    }
+    
+    // Every rate is bound by the platform fee cap
+    if tiers.iter().any(|tier| {
+        tier.buy_fee_bps > MAX_PLATFORM_FEE_BPS || tier.sell_fee_bps > MAX_PLATFORM_FEE_BPS
+    }) {
+        return Err(LaunchpadError::InvalidFeeBasisPoints.into());
+    }
+    
+    Ok(())
+}
+
+pub fn resolve_platform_fee_bps(
+    global_state: &GlobalState,
+    fee_schedule: Option<&FeeSchedule>,
+    creator_profile: &CreatorProfile,
+    launch_volume: u64,
+    side: &TradeSide,
+) -> Result<u16> {
+    // A negotiated creator rate takes precedence over the flat fee and tiers
+    if let Some(override_bps) = creator_profile.platform_fee_override_bps {
+        return Ok(override_bps);
+    }
+    
+    if !global_state.fee_schedule_enabled {
+        return Ok(global_state.platform_fee_bps);
+    }
+    
+    let fee_schedule = fee_schedule.ok_or(LaunchpadError::MissingFeeSchedule)?;
+    Ok(fee_schedule.fee_bps(launch_volume, side))
+}
+
+pub fn check_fee_override_decrease(
+    global_state: &GlobalState,
+    fee_schedule: Option<&FeeSchedule>,
+    creator_profile: &CreatorProfile,
+    platform_fee_bps: Option<u16>,
+) -> Result<()> {
+    // The override replaces the rate on both sides at every volume, so it must
+    // not exceed the lowest rate currently charged on any of them
+    let volumes = match fee_schedule {
+        Some(fee_schedule) if global_state.fee_schedule_enabled => {
+            fee_schedule.tiers.iter().map(|tier| tier.min_volume).collect()
+        }
+        _ => vec![0],
+    };
+    let mut current_bps = u16::MAX;
+    for volume in volumes {
+        for side in [TradeSide::Buy, TradeSide::Sell] {
+            let fee_bps = resolve_platform_fee_bps(global_state, fee_schedule, creator_profile, volume, &side)?;
+            current_bps = current_bps.min(fee_bps);
+        }
+    }
+    
+    // Immediate overrides may only lower the creator's fee; anything that
+    // could raise it (including clearing the override) is timelocked
+    match platform_fee_bps {
+        Some(fee_bps) if fee_bps <= current_bps => Ok(()),
+        _ => Err(LaunchpadError::FeeIncreaseRequiresTimelock.into()),
+    }
+}
+
+pub fn split_referral_fee(platform_fee: u64, referral_share_bps: u16) -> Result<(u64, u64)> {
+    // Referrers are paid out of the platform's cut, never on top of it
+    let referral_fee = calculate_fee(platform_fee, referral_share_bps)?;
+    let platform_fee = platform_fee.checked_sub(referral_fee)
+        .ok_or(LaunchpadError::ArithmeticUnderflow)?;
+    
+    Ok((platform_fee, referral_fee))
+}
+
+pub const MAX_SNIPER_FEE_BPS: u16 = 5000; // 50%
+
+pub fn calculate_sniper_fee_bps(
+    anti_snipe: &AntiSnipeParams,
+    normal_fee_bps: u16,
+    launch_start: i64,
+    current_time: i64,
+) -> u16 {
+    if anti_snipe.sniper_fee_bps <= normal_fee_bps {
+        return normal_fee_bps;
+    }
+    
+    let elapsed = current_time.saturating_sub(launch_start).max(0);
+    if elapsed >= anti_snipe.decay_seconds {
+        return normal_fee_bps;
+    }
+    
+    // Linear decay from sniper_fee_bps down to normal_fee_bps over decay_seconds
+    let surplus = (anti_snipe.sniper_fee_bps - normal_fee_bps) as i64;
+    let remaining = surplus - surplus * elapsed / anti_snipe.decay_seconds;
+    normal_fee_bps + remaining as u16
+}
+
 pub fn calculate_net_amount(amount: u64, fee_bps: u16) -> Result<u64> {
     let fee = calculate_fee(amount, fee_bps)?;
     amount.checked_sub(fee)
         .ok_or(LaunchpadError::ArithmeticUnderflow.into())
 }
 
+// ============================================================================
+// Presale Allowlist (Merkle Proofs)
+// ============================================================================
+
+pub fn presale_leaf(wallet: &Pubkey, max_allocation: u64) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), &max_allocation.to_le_bytes()]).to_bytes()
+}
+
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    // Pairs are hashed in sorted order so proofs don't need position flags
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    
+    computed == *root
+}
+
+pub fn public_trading_start(launchpad: &LaunchpadState) -> i64 {
+    // Public trading opens at the scheduled start, but never before a presale ends
+    let scheduled = launchpad.trading_starts_at.unwrap_or(launchpad.created_at);
+    match &launchpad.presale {
+        Some(presale) => scheduled.max(presale.end_time),
+        None => scheduled,
+    }
+}
+
+pub fn calculate_presale_tokens(
+    sol_amount: u64,
+    presale_price: Option<u64>,
+    current_supply: u64,
+    curve_type: &CurveType,
+    curve_params: &CurveParams,
+) -> Result<u64> {
+    let price = match presale_price {
+        Some(price) => price,
+        None => {
+            return calculate_tokens_for_sol_amount(sol_amount, current_supply, curve_type, curve_params);
+        }
+    };
+    
+    let tokens_out = sol_amount.checked_div(price)
+        .ok_or(LaunchpadError::DivisionByZero)?;
+    
+    // Presale tokens move supply along the curve, so later sells refund the
+    // curve cost; the reserves must cover it or the last sellers are stranded
+    let new_supply = current_supply.checked_add(tokens_out)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    let curve_cost = calculate_curve_cost(current_supply, new_supply, curve_type, curve_params)?;
+    if sol_amount < curve_cost {
+        return Err(LaunchpadError::PresalePriceBelowCurve.into());
+    }
+    
+    Ok(tokens_out)
+}
+
+pub fn check_public_trading_open(launchpad: &LaunchpadState, current_time: i64) -> Result<()> {
+    // Respect the creator's scheduled start
+    if let Some(trading_starts_at) = launchpad.trading_starts_at {
+        if current_time < trading_starts_at {
+            return Err(LaunchpadError::TradingNotStarted.into());
+        }
+    }
+    
+    // The curve only opens to the public once any presale has ended
+    if let Some(presale) = &launchpad.presale {
+        if current_time < presale.end_time {
+            return Err(LaunchpadError::PresaleActive.into());
+        }
+    }
+    
+    Ok(())
+}
+
+// ============================================================================
+// Trade Limits
+// ============================================================================
+
+pub fn init_trader_position(
+    trader_position: &mut TraderPosition,
+    launchpad: &mut LaunchpadState,
+    launchpad_key: Pubkey,
+    trader: Pubkey,
+    bump: u8,
+) {
+    trader_position.launchpad = launchpad_key;
+    trader_position.trader = trader;
+    trader_position.bump = bump;
+    
+    // Count the creator's initial buy so it can't be used to exceed the cap,
+    // but only once: a closed and reopened position must not count it again
+    if trader == launchpad.creator
+        && launchpad.creator_initial_buy_tokens > 0
+        && !launchpad.creator_position_seeded
+    {
+        launchpad.creator_position_seeded = true;
+        trader_position.tokens_bought = launchpad.creator_initial_buy_tokens;
+        trader_position.record_buy(
+            launchpad.created_at,
+            launchpad.creator_initial_buy_sol,
+            launchpad.creator_initial_buy_tokens,
+        );
+    }
+}
+
+pub fn track_wallet_buy(
+    trader_position: &mut TraderPosition,
+    max_tokens_per_wallet: Option<u64>,
+    tokens: u64,
+) -> Result<()> {
+    trader_position.tokens_bought = trader_position.tokens_bought
+        .checked_add(tokens)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    if max_tokens_per_wallet.is_some_and(|max_tokens| trader_position.tokens_bought > max_tokens) {
+        return Err(LaunchpadError::WalletLimitExceeded.into());
+    }
+    
+    Ok(())
+}
+
+// ============================================================================
+// Vesting Calculations
+// ============================================================================
+
+pub fn calculate_vested_amount(
+    total_amount: u64,
+    start_time: i64,
+    cliff_seconds: i64,
+    duration_seconds: i64,
+    current_time: i64,
+) -> Result<u64> {
+    let elapsed = current_time.saturating_sub(start_time);
+    
+    // Nothing unlocks before the cliff
+    if elapsed < cliff_seconds {
+        return Ok(0);
+    }
+    
+    // Everything unlocks once the full duration has passed
+    if elapsed >= duration_seconds {
+        return Ok(total_amount);
+    }
+    
+    // Linear unlock: total * elapsed / duration
+    let vested = (total_amount as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?
+        .checked_div(duration_seconds as u128)
+        .ok_or(LaunchpadError::DivisionByZero)?;
+    
+    Ok(vested as u64)
+}
+
+pub fn check_launch_failed(
+    curve_state: &BondingCurveState,
+    criteria: &GraduationCriteria,
+    failure_deadline: Option<i64>,
+    current_time: i64,
+) -> bool {
+    // A launch fails when its failure deadline passes while it still can't graduate
+    match failure_deadline {
+        Some(failure_deadline) => {
+            current_time >= failure_deadline
+                && !check_graduation_criteria(curve_state, criteria, current_time)
+        }
+        None => false,
+    }
+}
+
+pub fn check_launch_cancellable(
+    launchpad: &LaunchpadState,
+    curve_state: &BondingCurveState,
+    current_time: i64,
+) -> Result<()> {
+    match launchpad.status {
+        LaunchpadStatus::Graduated => return Err(LaunchpadError::LaunchpadAlreadyGraduated.into()),
+        LaunchpadStatus::Failed => return Err(LaunchpadError::LaunchpadFailed.into()),
+        _ => {}
+    }
+    
+    // Cancelling refunds the deposit, so it must not dodge a pending forfeit
+    if check_launch_failed(
+        curve_state,
+        &launchpad.graduation_criteria,
+        launchpad.failure_deadline,
+        current_time,
+    ) {
+        return Err(LaunchpadError::LaunchpadFailed.into());
+    }
+    
+    // Any trade, even one fully sold back, leaves fees tied to the launchpad
+    if curve_state.supply_sold > 0 || curve_state.total_volume > 0 {
+        return Err(LaunchpadError::LaunchpadHasTrades.into());
+    }
+    
+    Ok(())
+}
+
+// ============================================================================
+// Reputation Scoring
+// ============================================================================
+
+pub const MAX_REPUTATION_SCORE: u16 = 1000;
+pub const REPUTATION_SUCCESS_WEIGHT: u64 = 700;      // Scaled by graduation rate
+pub const REPUTATION_PER_GRADUATION: u64 = 20;       // Experience per graduated launch
+pub const REPUTATION_EXPERIENCE_CAP: u64 = 200;      // Max experience points
+pub const REPUTATION_VERIFIED_BONUS: u64 = 100;      // Bonus for verified creators
+
+pub fn calculate_reputation_score(
+    launches_count: u32,
+    successful_launches: u32,
+    verified: bool,
+    adjustment: i16,
+) -> u16 {
+    // Graduation rate component: successful / launches * weight
+    let success_component = if launches_count == 0 {
+        0
+    } else {
+        (successful_launches as u64).min(launches_count as u64) * REPUTATION_SUCCESS_WEIGHT
+            / launches_count as u64
+    };
+    
+    // Experience component rewards a track record of graduations
+    let experience_component = (successful_launches as u64 * REPUTATION_PER_GRADUATION)
+        .min(REPUTATION_EXPERIENCE_CAP);
+    
+    let verified_component = if verified { REPUTATION_VERIFIED_BONUS } else { 0 };
+    
+    let base = (success_component + experience_component + verified_component) as i64;
+    
+    // Apply admin adjustment and clamp to the valid range
+    (base + adjustment as i64).clamp(0, MAX_REPUTATION_SCORE as i64) as u16
+}
+
+pub fn update_reputation_score(profile: &mut CreatorProfile) {
+    profile.reputation_score = calculate_reputation_score(
+        profile.launches_count,
+        profile.successful_launches,
+        profile.verified,
+        profile.reputation_adjustment,
+    );
+}
+
 // ============================================================================
 // Graduation Criteria Validation
 // ============================================================================
@@ -261,10 +860,78 @@ pub fn check_graduation_criteria(
     true
 }
 
+// ============================================================================
+// Launch Status Transitions
+// ============================================================================
+
+pub fn toggle_launch_pause(launchpad: &mut LaunchpadState) -> Result<()> {
+    match launchpad.status {
+        LaunchpadStatus::Active | LaunchpadStatus::SellOnly => {
+            // Remember whether the launch was sell-only so unpausing restores it
+            launchpad.resume_status = launchpad.status.clone();
+            launchpad.status = LaunchpadStatus::Paused;
+        }
+        LaunchpadStatus::Paused => {
+            launchpad.status = launchpad.resume_status.clone();
+        }
+        LaunchpadStatus::Graduated => {
+            return Err(LaunchpadError::LaunchpadAlreadyGraduated.into());
+        }
+        LaunchpadStatus::Failed => {
+            return Err(LaunchpadError::LaunchpadNotActive.into());
+        }
+    }
+    
+    Ok(())
+}
+
+pub fn set_launch_sell_only(launchpad: &mut LaunchpadState, enabled: bool) -> Result<()> {
+    launchpad.status = match (&launchpad.status, enabled) {
+        (LaunchpadStatus::Active | LaunchpadStatus::SellOnly, true) => LaunchpadStatus::SellOnly,
+        (LaunchpadStatus::Active | LaunchpadStatus::SellOnly, false) => LaunchpadStatus::Active,
+        (LaunchpadStatus::Graduated, _) => {
+            return Err(LaunchpadError::LaunchpadAlreadyGraduated.into());
+        }
+        (LaunchpadStatus::Paused | LaunchpadStatus::Failed, _) => {
+            return Err(LaunchpadError::LaunchpadNotActive.into());
+        }
+    };
+    
+    Ok(())
+}
+
 // ============================================================================
 // Validation Helpers
 // ============================================================================
 
+pub fn validate_admin_action(action: &AdminAction) -> Result<()> {
+    match action {
+        AdminAction::SetPlatformFee { platform_fee_bps } => {
+            if *platform_fee_bps > MAX_PLATFORM_FEE_BPS {
+                return Err(LaunchpadError::InvalidFeeBasisPoints.into());
+            }
+        }
+        AdminAction::SetFeeTiers { tiers } => validate_fee_tiers(tiers)?,
+        AdminAction::TransferAuthority { new_authority } => {
+            if *new_authority == Pubkey::default() {
+                return Err(LaunchpadError::InvalidAdminAction.into());
+            }
+        }
+        AdminAction::SetTreasury { treasury } => {
+            if *treasury == Pubkey::default() {
+                return Err(LaunchpadError::InvalidAdminAction.into());
+            }
+        }
+        AdminAction::SetCreatorFeeOverride { platform_fee_bps, .. } => {
+            if platform_fee_bps.is_some_and(|fee_bps| fee_bps > MAX_PLATFORM_FEE_BPS) {
+                return Err(LaunchpadError::InvalidFeeBasisPoints.into());
+            }
+        }
+    }
+    
+    Ok(())
+}
+
 pub fn validate_create_params(params: &CreateLaunchpadParams) -> Result<()> {
     // Validate name length
     if params.name.len() > 32 {
@@ -296,6 +963,64 @@ pub fn validate_create_params(params: &CreateLaunchpadParams) -> Result<()> {
         return Err(LaunchpadError::InvalidCurveParams.into());
     }
     
+    // Validate vesting parameters if set
+    let vesting_amount = match &params.vesting {
+        Some(vesting) => {
+            if vesting.amount == 0
+                || vesting.duration_seconds <= 0
+                || vesting.cliff_seconds < 0
+                || vesting.cliff_seconds > vesting.duration_seconds
+            {
+                return Err(LaunchpadError::InvalidVestingParams.into());
+            }
+            vesting.amount
+        }
+        None => 0,
+    };
+    
+    // Validate supply allocations: curve + LP reserve + vesting must equal total supply
+    let allocated_supply = params.curve_params.max_supply
+        .checked_add(params.lp_reserve)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?
+        .checked_add(vesting_amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    if allocated_supply != params.total_supply {
+        return Err(LaunchpadError::InvalidSupplyAllocation.into());
+    }
+    
+    // Validate initial buy amount if set
+    if params.initial_buy_sol == Some(0) {
+        return Err(LaunchpadError::InsufficientSolAmount.into());
+    }
+    
+    // Validate trade limits if set
+    if params.max_tokens_per_wallet == Some(0) || params.max_sol_per_tx == Some(0) {
+        return Err(LaunchpadError::InvalidTradeLimits.into());
+    }
+    
+    // Validate presale configuration if set
+    if let Some(presale) = &params.presale {
+        let current_time = Clock::get()?.unix_timestamp;
+        if presale.start_time >= presale.end_time
+            || presale.end_time <= current_time
+            || presale.merkle_root == [0u8; 32]
+            || presale.presale_price.is_some_and(|price| price == 0 || price < params.curve_params.base_price)
+        {
+            return Err(LaunchpadError::InvalidPresaleConfig.into());
+        }
+    }
+    
+    // Validate anti-snipe parameters if set
+    if let Some(anti_snipe) = &params.anti_snipe {
+        if anti_snipe.start_delay_seconds < 0
+            || anti_snipe.max_buy_window_seconds < 0
+            || anti_snipe.decay_seconds < 0
+            || anti_snipe.sniper_fee_bps > MAX_SNIPER_FEE_BPS
+        {
+            return Err(LaunchpadError::InvalidAntiSnipeParams.into());
+        }
+    }
+    
     // Validate time limit if set
     if let Some(time_limit) = params.graduation_criteria.time_limit {
         let current_time = Clock::get()?.unix_timestamp;
@@ -304,6 +1029,54 @@ pub fn validate_create_params(params: &CreateLaunchpadParams) -> Result<()> {
         }
     }
     
+    // Validate scheduled trading start if set
+    if let Some(trading_starts_at) = params.trading_starts_at {
+        let current_time = Clock::get()?.unix_timestamp;
+        if trading_starts_at <= current_time {
+            return Err(LaunchpadError::InvalidTradingStartTime.into());
+        }
+        
+        // Trading must open before the graduation deadline
+        if let Some(time_limit) = params.graduation_criteria.time_limit {
+            if trading_starts_at >= time_limit {
+                return Err(LaunchpadError::InvalidTradingStartTime.into());
+            }
+        }
+    }
+    
+    // Validate failure deadline if set: it must leave time to trade and graduate
+    if let Some(failure_deadline) = params.failure_deadline {
+        let current_time = Clock::get()?.unix_timestamp;
+        let earliest = [
+            params.trading_starts_at,
+            params.graduation_criteria.time_limit,
+            params.presale.as_ref().map(|presale| presale.end_time),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(current_time, i64::max);
+        
+        if failure_deadline <= earliest {
+            return Err(LaunchpadError::InvalidFailureDeadline.into());
+        }
+    }
+    
+    Ok(())
+}
+
+pub fn check_curve_vault_balance(
+    vault_amount: u64,
+    supply_sold: u64,
+    curve_allocation: u64,
+) -> Result<()> {
+    // Every token sold via the curve must have left the vault and vice versa
+    let accounted = vault_amount.checked_add(supply_sold)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    
+    if accounted != curve_allocation {
+        return Err(LaunchpadError::CurveVaultMismatch.into());
+    }
+    
     Ok(())
 }
 